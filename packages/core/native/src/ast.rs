@@ -6,6 +6,15 @@ pub fn build_decorated_string<'alloc>(
     ast_builder: &AstBuilder<'alloc>,
     span: Span,
     content: &str,
+) -> Expression<'alloc> {
+    build_new_string(ast_builder, span, build_string(ast_builder, span, content))
+}
+
+/// `new String(value)`, a string that can hold properties like `.css`
+pub fn build_new_string<'alloc>(
+    ast_builder: &AstBuilder<'alloc>,
+    span: Span,
+    value: Expression<'alloc>,
 ) -> Expression<'alloc> {
     Expression::NewExpression(ast_builder.alloc_new_expression(
         span,
@@ -13,9 +22,7 @@ pub fn build_decorated_string<'alloc>(
             ast_builder.alloc_identifier_reference(span, ast_builder.atom("String")),
         ),
         None as Option<oxc_allocator::Box<_>>,
-        ast_builder.vec1(oxc_ast::ast::Argument::StringLiteral(
-            ast_builder.alloc_string_literal(span, ast_builder.atom(content), None),
-        )),
+        ast_builder.vec1(oxc_ast::ast::Argument::from(value)),
     ))
 }
 
@@ -31,6 +38,26 @@ pub fn build_string<'alloc>(
     ))
 }
 
+/// `left + right`, merging adjacent string literals so static strings stay a single literal
+pub fn build_string_concat<'alloc>(
+    ast_builder: &AstBuilder<'alloc>,
+    span: Span,
+    left: Expression<'alloc>,
+    right: Expression<'alloc>,
+) -> Expression<'alloc> {
+    match (left, right) {
+        (Expression::StringLiteral(left), Expression::StringLiteral(right)) => {
+            build_string(ast_builder, span, &format!("{}{}", left.value, right.value))
+        }
+        (left, right) => Expression::BinaryExpression(ast_builder.alloc_binary_expression(
+            span,
+            left,
+            oxc_ast::ast::BinaryOperator::Addition,
+            right,
+        )),
+    }
+}
+
 pub fn build_undefined<'alloc>(ast_builder: &AstBuilder<'alloc>, span: Span) -> Expression<'alloc> {
     Expression::Identifier(
         ast_builder.alloc_identifier_reference(span, ast_builder.atom("undefined")),
@@ -69,6 +96,25 @@ pub fn build_identifier<'alloc>(
     Expression::Identifier(ast_builder.alloc_identifier_reference(span, ast_builder.atom(name)))
 }
 
+/// builds `st.css` from a path returned by `utils::expression_path`
+pub fn build_expression_path<'alloc>(
+    ast_builder: &AstBuilder<'alloc>,
+    span: Span,
+    path: &str,
+) -> Expression<'alloc> {
+    let mut parts = path.split('.');
+    let mut expression = build_identifier(ast_builder, span, parts.next().unwrap_or_default());
+    for part in parts {
+        expression = Expression::StaticMemberExpression(ast_builder.alloc_static_member_expression(
+            span,
+            expression,
+            ast_builder.identifier_name(span, ast_builder.atom(part)),
+            false,
+        ));
+    }
+    expression
+}
+
 #[allow(dead_code)]
 pub fn build_assignment<'alloc>(
     ast_builder: &AstBuilder<'alloc>,
@@ -149,3 +195,27 @@ pub fn build_variable_declaration_ident<'alloc>(
         false,
     ))
 }
+
+/// builds `{ "--var": value }`, the inline style setting the custom properties of a class
+pub fn build_custom_properties<'alloc>(
+    ast_builder: &AstBuilder<'alloc>,
+    span: Span,
+    properties: Vec<(String, Expression<'alloc>)>,
+) -> Expression<'alloc> {
+    Expression::ObjectExpression(ast_builder.alloc_object_expression(
+        span,
+        ast_builder.vec_from_iter(properties.into_iter().map(|(name, value)| {
+            ast_builder.object_property_kind_object_property(
+                span,
+                oxc_ast::ast::PropertyKind::Init,
+                ast_builder
+                    .expression_string_literal(span, ast_builder.atom(&name), None)
+                    .into(),
+                value,
+                false,
+                false,
+                false,
+            )
+        })),
+    ))
+}
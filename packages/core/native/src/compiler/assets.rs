@@ -0,0 +1,213 @@
+use super::error::TransformError;
+use crate::PREFIX;
+use oxc_syntax::identifier::is_identifier_name;
+use oxc_syntax::keyword::is_reserved_keyword_or_global_object;
+use std::path::Path;
+
+/// Non-JS dependencies that can still be imported by styled files
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum AssetKind {
+    /// `.json` files, exposed as default export and top-level named exports
+    Json,
+    /// text files imported with `?raw` or with a configured raw extension
+    Raw,
+}
+
+impl AssetKind {
+    /// detects asset modules from the import specifier and the resolved filepath
+    pub fn detect(module_id: &str, filepath: &str, raw_extensions: &[String]) -> Option<Self> {
+        let has_raw_query = module_id
+            .split_once('?')
+            .is_some_and(|(_, query)| query.split('&').any(|param| param == "raw"));
+
+        if has_raw_query {
+            return Some(AssetKind::Raw);
+        }
+
+        let extension = Path::new(filepath).extension()?.to_str()?;
+
+        if raw_extensions
+            .iter()
+            .any(|raw_extension| raw_extension.trim_start_matches('.') == extension)
+        {
+            return Some(AssetKind::Raw);
+        }
+
+        if extension == "json" {
+            return Some(AssetKind::Json);
+        }
+
+        None
+    }
+
+    /// generates the source of an ES module exposing the asset contents
+    pub fn to_module_code(self, filepath: &str, code: &str) -> Result<String, TransformError> {
+        match self {
+            AssetKind::Json => json_to_module_code(filepath, code),
+            AssetKind::Raw => Ok(format!("export default {};\n", js_string_literal(code))),
+        }
+    }
+}
+
+fn json_to_module_code(filepath: &str, code: &str) -> Result<String, TransformError> {
+    // parse as strict JSON so JS-only syntax (unquoted keys, comments, ...) is rejected
+    let value: serde_json::Value =
+        serde_json::from_str(code).map_err(|error| TransformError::RawParseFailed {
+            message: error.to_string(),
+            filepath: filepath.to_string(),
+            row: error.line(),
+            column: error.column(),
+        })?;
+
+    // JSON.parse keeps keys like `__proto__` own properties, an object literal wouldn't
+    let json_variable_name = format!("{PREFIX}_json");
+    let mut module_code = format!(
+        "const {json_variable_name} = JSON.parse({});\nexport default {json_variable_name};\n",
+        js_string_literal(code)
+    );
+
+    // only plain object keys that are valid binding names become named exports
+    let serde_json::Value::Object(object) = &value else {
+        return Ok(module_code);
+    };
+
+    for name in object.keys() {
+        if !is_identifier_name(name)
+            || is_reserved_keyword_or_global_object(name)
+            || *name == json_variable_name
+        {
+            continue;
+        }
+
+        module_code.push_str(&format!(
+            "export const {name} = {json_variable_name}[{}];\n",
+            js_string_literal(name)
+        ));
+    }
+
+    Ok(module_code)
+}
+
+/// quotes a string as a double-quoted JS string literal
+fn js_string_literal(value: &str) -> String {
+    let mut ret = String::with_capacity(value.len() + 2);
+    ret.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => ret.push_str("\\\""),
+            '\\' => ret.push_str("\\\\"),
+            '\n' => ret.push_str("\\n"),
+            '\r' => ret.push_str("\\r"),
+            '\t' => ret.push_str("\\t"),
+            '\u{2028}' => ret.push_str("\\u2028"),
+            '\u{2029}' => ret.push_str("\\u2029"),
+            ch if ch.is_control() => ret.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => ret.push(ch),
+        }
+    }
+    ret.push('"');
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_asset_kind() {
+        let raw_extensions = vec!["svg".to_string(), ".txt".to_string()];
+
+        assert_eq!(
+            AssetKind::detect("./tokens.json", "/p/tokens.json", &[]),
+            Some(AssetKind::Json)
+        );
+        assert_eq!(
+            AssetKind::detect("./icon.svg?raw", "/p/icon.svg", &[]),
+            Some(AssetKind::Raw)
+        );
+        assert_eq!(
+            AssetKind::detect("./data.json?raw", "/p/data.json", &[]),
+            Some(AssetKind::Raw)
+        );
+        assert_eq!(
+            AssetKind::detect("./icon.svg", "/p/icon.svg", &raw_extensions),
+            Some(AssetKind::Raw)
+        );
+        assert_eq!(
+            AssetKind::detect("./notes.txt", "/p/notes.txt", &raw_extensions),
+            Some(AssetKind::Raw)
+        );
+        assert_eq!(AssetKind::detect("./icon.svg", "/p/icon.svg", &[]), None);
+        assert_eq!(AssetKind::detect("./theme", "/p/theme.ts", &[]), None);
+    }
+
+    #[test]
+    fn test_json_to_module_code() {
+        let code = AssetKind::Json
+            .to_module_code(
+                "tokens.json",
+                r#"{ "primary": "red", "font-size": 12, "default": 1, "primary": "blue" }"#,
+            )
+            .unwrap();
+
+        assert!(code.contains("export default __styleThis_json;"));
+        assert!(code.contains(r#"export const primary = __styleThis_json["primary"];"#));
+        assert_eq!(code.matches("export const primary").count(), 1);
+        assert!(!code.contains("export const font-size"));
+        assert!(!code.contains("export const default"));
+    }
+
+    #[test]
+    fn test_json_proto_key_is_a_property() {
+        let code = AssetKind::Json
+            .to_module_code("tokens.json", r#"{ "__proto__": { "a": 1 } }"#)
+            .unwrap();
+
+        assert!(code.starts_with(
+            r#"const __styleThis_json = JSON.parse("{ \"__proto__\": { \"a\": 1 } }");"#
+        ));
+        assert!(code.contains(r#"export const __proto__ = __styleThis_json["__proto__"];"#));
+    }
+
+    #[test]
+    fn test_json_array_has_default_export_only() {
+        let code = AssetKind::Json
+            .to_module_code("list.json", "[1, 2, 3]")
+            .unwrap();
+
+        assert!(code.contains("export default __styleThis_json;"));
+        assert!(!code.contains("export const"));
+    }
+
+    #[test]
+    fn test_invalid_json() {
+        let err = AssetKind::Json.to_module_code("broken.json", "{ \"a\": ");
+        assert!(matches!(err, Err(TransformError::RawParseFailed { .. })));
+    }
+
+    #[test]
+    fn test_non_json_object_syntax_is_rejected() {
+        for code in [
+            "{ primary: \"red\" }",
+            "{ \"primary\": 'red' }",
+            "{ \"primary\": \"red\", }",
+            "{ /* comment */ \"primary\": \"red\" }",
+            "{ \"primary\": `red` }",
+        ] {
+            let err = AssetKind::Json.to_module_code("tokens.json", code);
+            assert!(
+                matches!(err, Err(TransformError::RawParseFailed { .. })),
+                "accepted {code}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_raw_to_module_code() {
+        let code = AssetKind::Raw
+            .to_module_code("icon.svg", "<svg fill=\"red\">\n</svg>")
+            .unwrap();
+
+        assert_eq!(code, "export default \"<svg fill=\\\"red\\\">\\n</svg>\";\n");
+    }
+}
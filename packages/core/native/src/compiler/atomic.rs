@@ -101,11 +101,11 @@ fn parse_css_recursive(css: &str, declarations: &mut Vec<CssDeclaration>) {
                     let block_content = current_block.trim();
                     if !block_content.is_empty() {
                         // Find the content inside braces
-                        if let Some(start) = block_content.find('{') {
-                            if let Some(end) = block_content.rfind('}') {
-                                let inner = &block_content[start + 1..end];
-                                parse_css_recursive(inner, declarations);
-                            }
+                        if let Some(start) = block_content.find('{')
+                            && let Some(end) = block_content.rfind('}')
+                        {
+                            let inner = &block_content[start + 1..end];
+                            parse_css_recursive(inner, declarations);
                         }
                     }
                     current_block.clear();
@@ -205,6 +205,18 @@ pub fn css_to_atomic_classes(css: &str) -> Vec<String> {
         .collect()
 }
 
+/// Maps the atomic classes of a block of CSS to the property they set, classes setting the
+/// same property override each other when merged with `cx`
+pub fn css_to_atomic_class_keys(css: &str) -> Vec<(String, String)> {
+    parse_css_declarations(css)
+        .into_iter()
+        .map(|decl| {
+            let class_name = get_atomic_class(&decl.property, &decl.value);
+            (class_name, decl.property.trim().to_string())
+        })
+        .collect()
+}
+
 /// Extract the non-atomizable CSS (media queries, nested selectors, etc.)
 /// This removes simple top-level declarations but preserves complex structures
 pub fn extract_non_atomic_css(css: &str) -> String {
@@ -288,6 +300,19 @@ pub fn css_to_atomic_class_list(css: &str) -> String {
     css_to_atomic_classes(css).join(" ")
 }
 
+/// Parse CSS and return a JSON object mapping its atomic classes to their property (JavaScript API)
+#[wasm_bindgen]
+pub fn css_to_atomic_class_keys_js(css: &str) -> String {
+    let entries = css_to_atomic_class_keys(css)
+        .into_iter()
+        .map(|(class_name, property)| {
+            let property = property.replace('\\', "\\\\").replace('"', "\\\"");
+            format!("\"{class_name}\":\"{property}\"")
+        })
+        .collect::<Vec<_>>();
+    format!("{{{}}}", entries.join(","))
+}
+
 /// Extract non-atomizable CSS like media queries and nested selectors (JavaScript API)
 #[wasm_bindgen]
 pub fn extract_non_atomic_css_js(css: &str) -> String {
@@ -361,6 +386,24 @@ mod tests {
         assert_eq!(classes[1].len(), 5);
     }
 
+    #[test]
+    fn test_css_to_atomic_class_keys() {
+        let keys = css_to_atomic_class_keys("color: red; padding: 4px;");
+        let classes = css_to_atomic_classes("color: red; padding: 4px;");
+
+        assert_eq!(
+            keys,
+            vec![
+                (classes[0].clone(), "color".to_string()),
+                (classes[1].clone(), "padding".to_string()),
+            ]
+        );
+        assert_eq!(
+            css_to_atomic_class_keys_js("color: red;"),
+            format!("{{\"{}\":\"color\"}}", classes[0])
+        );
+    }
+
     #[test]
     fn test_parse_media_queries() {
         clear_atomic_cache();
@@ -3,8 +3,19 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::rc::Rc;
+use wasm_bindgen::prelude::*;
 
 thread_local! {
-    pub(crate) static CSS_CLASSNAME_CACHE: RefCell<HashMap<String, HashMap<u32, String>>> = RefCell::new(HashMap::new());
+    /// filepath -> what a name is rendered from (see `ClassNameParts::cache_key`) -> class name
+    pub(crate) static CSS_CLASSNAME_CACHE: RefCell<HashMap<String, HashMap<String, String>>> = RefCell::new(HashMap::new());
+    /// generated class name -> file it was generated for, two files can't generate the same name
+    pub(crate) static CLASS_NAME_OWNERS: RefCell<HashMap<String, String>> = RefCell::new(HashMap::new());
     pub(crate) static VALUE_CACHE: RefCell<HashMap<String, Rc<FutureMutex<HashSet<String>>>>> = RefCell::new(HashMap::new());
 }
+
+/// Frees the class names of a deleted file, so other files can generate them (JavaScript API)
+#[wasm_bindgen]
+pub fn release_class_names(filepath: String) {
+    CLASS_NAME_OWNERS.with(|owners| owners.borrow_mut().retain(|_, owner| *owner != filepath));
+    CSS_CLASSNAME_CACHE.with(|cache| cache.borrow_mut().remove(&filepath));
+}
@@ -0,0 +1,479 @@
+use crate::utils::SeededRandom;
+use oxc_ast::ast::{
+    AssignmentExpression, AssignmentTarget, BindingPatternKind, Class, Expression, Function,
+    JSXElement, ObjectProperty, Program, PropertyDefinition, VariableDeclarator,
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_semantic::ScopeFlags;
+use oxc_span::Span;
+use std::path::Path;
+
+/// the names generated before the `classNameTemplate` option existed
+const DEFAULT_TEMPLATE: &str = "[name]-[hash:6]";
+/// part of every hash seed, bumped whenever the hashing changes so it's a deliberate change
+const HASH_VERSION: &str = "v1";
+
+/// Pattern of generated class names from the `classNameTemplate` option, e.g.
+/// `[file]_[name]_[hash:4]` or `[hash:8]`
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ClassNameTemplate {
+    segments: Vec<Segment>,
+    /// from the `hashSalt` option, changes every hash of the project
+    hash_salt: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Literal(String),
+    /// variable name of the block, or a name derived from what contains it
+    /// (see `BindingScopes::display_name`)
+    Name,
+    /// file name without extension
+    File,
+    /// name of the directory containing the file
+    Dir,
+    /// line of the template
+    Line,
+    /// hash of the file path and the block key with the given length
+    Hash(usize),
+}
+
+/// What a class name is generated from
+pub(crate) struct ClassNameParts<'a> {
+    pub name: &'a str,
+    /// path relative to the project root, see `project_relative_path`
+    pub filepath: &'a str,
+    pub line: usize,
+    /// identifies the block within the file
+    pub block_key: &'a str,
+}
+
+impl ClassNameParts<'_> {
+    /// identifies what a name is rendered from, the file is left out as names are cached per
+    /// file
+    pub fn cache_key(&self) -> String {
+        format!("{}\0{}\0{}", self.block_key, self.name, self.line)
+    }
+}
+
+impl Default for ClassNameTemplate {
+    fn default() -> Self {
+        Self::parse(DEFAULT_TEMPLATE).unwrap()
+    }
+}
+
+impl ClassNameTemplate {
+    /// parses a pattern, rejecting unknown placeholders and characters not allowed in class
+    /// names
+    pub fn parse(template: &str) -> Result<Self, String> {
+        let mut segments = vec![];
+        let mut rest = template;
+
+        while !rest.is_empty() {
+            if let Some(placeholder) = rest.strip_prefix('[') {
+                let Some(end) = placeholder.find(']') else {
+                    return Err(format!("unclosed placeholder in class name template '{template}'"));
+                };
+                segments.push(match &placeholder[..end] {
+                    "name" => Segment::Name,
+                    "file" => Segment::File,
+                    "dir" => Segment::Dir,
+                    "line" => Segment::Line,
+                    "hash" => Segment::Hash(6),
+                    other => match other.strip_prefix("hash:").map(str::parse) {
+                        Some(Ok(length @ 1..=32)) => Segment::Hash(length),
+                        Some(_) => {
+                            return Err(format!(
+                                "hash length in class name template '{template}' must be between 1 and 32"
+                            ));
+                        }
+                        None => {
+                            return Err(format!(
+                                "unknown placeholder '[{other}]' in class name template '{template}'"
+                            ));
+                        }
+                    },
+                });
+                rest = &placeholder[end + 1..];
+                continue;
+            }
+
+            let end = rest.find('[').unwrap_or(rest.len());
+            let literal = &rest[..end];
+            if let Some(ch) = literal.chars().find(|ch| !is_identifier_char(*ch)) {
+                return Err(format!(
+                    "class name template '{template}' contains '{ch}', which isn't allowed in class names"
+                ));
+            }
+            segments.push(Segment::Literal(literal.to_string()));
+            rest = &rest[end..];
+        }
+
+        if segments.is_empty() {
+            return Err("class name template is empty".to_string());
+        }
+
+        Ok(Self {
+            segments,
+            hash_salt: String::new(),
+        })
+    }
+
+    pub fn with_hash_salt(mut self, hash_salt: String) -> Self {
+        self.hash_salt = hash_salt;
+        self
+    }
+
+    /// fills in the placeholders, the result is always a valid CSS identifier
+    pub fn render(&self, parts: &ClassNameParts, random: &mut SeededRandom) -> String {
+        let path = Path::new(parts.filepath);
+        let hash_seed = format!(
+            "{HASH_VERSION}\0{}\0{}\0{}",
+            self.hash_salt, parts.filepath, parts.block_key
+        );
+        let file_name = |path: Option<&Path>, stem: bool| {
+            path.and_then(|path| if stem { path.file_stem() } else { path.file_name() })
+                .map(|name| sanitize(&name.to_string_lossy()))
+                .unwrap_or_default()
+        };
+
+        let mut class_name = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(literal) => class_name.push_str(literal),
+                Segment::Name => class_name.push_str(&sanitize(parts.name)),
+                Segment::File => class_name.push_str(&file_name(Some(path), true)),
+                Segment::Dir => class_name.push_str(&file_name(path.parent(), false)),
+                Segment::Line => class_name.push_str(&parts.line.to_string()),
+                Segment::Hash(length) => {
+                    class_name.push_str(&random.random_string(*length, &hash_seed))
+                }
+            }
+        }
+
+        // identifiers can't start with a digit, a hyphen and a digit, or two hyphens
+        let mut chars = class_name.chars();
+        let needs_prefix = match (chars.next(), chars.next()) {
+            (None, _) => true,
+            (Some(first), _) if first.is_ascii_digit() => true,
+            (Some('-'), Some(second)) => second == '-' || second.is_ascii_digit(),
+            (Some('-'), None) => true,
+            _ => false,
+        };
+        if needs_prefix {
+            class_name.insert(0, '_');
+        }
+
+        class_name
+    }
+}
+
+/// The file path relative to the project root with POSIX separators, so hashes are the same
+/// for every checkout of the project. Files outside of it are identified by their path within
+/// `node_modules` if they're in one (e.g. symlinked packages), or by a path with `..` segments.
+pub(crate) fn project_relative_path(project_root: &str, filepath: &str) -> String {
+    let project_root = project_root.replace('\\', "/");
+    let filepath = filepath.replace('\\', "/");
+
+    if let Some(relative) = filepath
+        .strip_prefix(project_root.trim_end_matches('/'))
+        .and_then(|relative| relative.strip_prefix('/'))
+    {
+        return relative.to_string();
+    }
+    if let Some((_, package_path)) = filepath.rsplit_once("/node_modules/") {
+        return package_path.to_string();
+    }
+
+    let root_segments = project_root.split('/').filter(|segment| !segment.is_empty());
+    let mut file_segments = filepath.split('/').filter(|segment| !segment.is_empty()).peekable();
+    let mut parents = 0;
+    for root_segment in root_segments {
+        if parents == 0 && file_segments.peek() == Some(&root_segment) {
+            file_segments.next();
+        } else {
+            parents += 1;
+        }
+    }
+
+    std::iter::repeat_n("..", parents)
+        .chain(file_segments)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Spans of the named declarations of a file, so css blocks are identified by where they are
+/// declared instead of their position, which changes whenever a block is added above them
+#[derive(Debug, Default)]
+pub(crate) struct BindingScopes {
+    /// in source order, so outer declarations come before the ones nested in them
+    scopes: Vec<(Span, String, ScopeKind)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ScopeKind {
+    Declaration,
+    /// object property keys, class fields and assignment targets, e.g. `root` in
+    /// `{ root: css`...` }` or `styles.root = css`...``
+    Property,
+    JsxElement,
+}
+
+impl BindingScopes {
+    pub fn collect(program: &Program) -> Self {
+        let mut binding_scopes = Self::default();
+        binding_scopes.visit_program(program);
+        binding_scopes
+    }
+
+    fn containing(&self, span: Span) -> impl Iterator<Item = &(Span, String, ScopeKind)> {
+        self.scopes
+            .iter()
+            .filter(move |(scope, ..)| scope.start <= span.start && span.end <= scope.end)
+    }
+
+    /// names of the declarations containing the span, e.g. `Card.button`
+    pub fn path(&self, span: Span) -> String {
+        self.containing(span)
+            .filter(|(.., kind)| *kind == ScopeKind::Declaration)
+            .map(|(_, name, _)| name.as_str())
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// readable name for a block without a variable of its own, from the declarations and
+    /// property keys containing it plus the innermost JSX element, e.g. `styles-root` or
+    /// `Card-div`, `None` if nothing contains it
+    pub fn display_name(&self, span: Span) -> Option<String> {
+        let scopes = self.containing(span).collect::<Vec<_>>();
+        let innermost_element = scopes
+            .iter()
+            .rposition(|(.., kind)| *kind == ScopeKind::JsxElement);
+
+        let names = scopes
+            .iter()
+            .enumerate()
+            .filter(|(idx, (.., kind))| {
+                *kind != ScopeKind::JsxElement || Some(*idx) == innermost_element
+            })
+            .map(|(_, (_, name, _))| name.as_str())
+            .collect::<Vec<_>>();
+
+        (!names.is_empty()).then(|| names.join("-"))
+    }
+
+    fn push(&mut self, span: Span, name: String, kind: ScopeKind) {
+        self.scopes.push((span, name, kind));
+    }
+}
+
+impl<'a> Visit<'a> for BindingScopes {
+    fn visit_variable_declarator(&mut self, it: &VariableDeclarator<'a>) {
+        if let BindingPatternKind::BindingIdentifier(ident) = &it.id.kind {
+            self.push(it.span, ident.name.to_string(), ScopeKind::Declaration);
+        }
+        walk::walk_variable_declarator(self, it);
+    }
+
+    fn visit_function(&mut self, it: &Function<'a>, flags: ScopeFlags) {
+        if let Some(id) = &it.id {
+            self.push(it.span, id.name.to_string(), ScopeKind::Declaration);
+        }
+        walk::walk_function(self, it, flags);
+    }
+
+    fn visit_class(&mut self, it: &Class<'a>) {
+        if let Some(id) = &it.id {
+            self.push(it.span, id.name.to_string(), ScopeKind::Declaration);
+        }
+        walk::walk_class(self, it);
+    }
+
+    fn visit_object_property(&mut self, it: &ObjectProperty<'a>) {
+        if let Some(key) = it.key.static_name() {
+            self.push(it.span, key.to_string(), ScopeKind::Property);
+        }
+        walk::walk_object_property(self, it);
+    }
+
+    fn visit_property_definition(&mut self, it: &PropertyDefinition<'a>) {
+        if let Some(key) = it.key.static_name() {
+            self.push(it.span, key.to_string(), ScopeKind::Property);
+        }
+        walk::walk_property_definition(self, it);
+    }
+
+    fn visit_assignment_expression(&mut self, it: &AssignmentExpression<'a>) {
+        let name = match &it.left {
+            AssignmentTarget::AssignmentTargetIdentifier(ident) => Some(ident.name.to_string()),
+            // `this.root = ...` is named `root`, `styles.root = ...` `styles-root`
+            AssignmentTarget::StaticMemberExpression(member) => match &member.object {
+                Expression::Identifier(object) => {
+                    Some(format!("{}-{}", object.name, member.property.name))
+                }
+                _ => Some(member.property.name.to_string()),
+            },
+            _ => None,
+        };
+        if let Some(name) = name {
+            self.push(it.span, name, ScopeKind::Property);
+        }
+        walk::walk_assignment_expression(self, it);
+    }
+
+    fn visit_jsx_element(&mut self, it: &JSXElement<'a>) {
+        self.push(
+            it.span,
+            it.opening_element.name.to_string(),
+            ScopeKind::JsxElement,
+        );
+        walk::walk_jsx_element(self, it);
+    }
+}
+
+fn is_identifier_char(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || ch == '_' || ch == '-'
+}
+
+/// replaces characters that would need escaping in a selector
+fn sanitize(value: &str) -> String {
+    value
+        .chars()
+        .map(|ch| if is_identifier_char(ch) { ch } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(template: &str, name: &str) -> String {
+        ClassNameTemplate::parse(template).unwrap().render(
+            &ClassNameParts {
+                name,
+                filepath: "src/components/Button.tsx",
+                line: 12,
+                block_key: "Button.root",
+            },
+            &mut SeededRandom::new(),
+        )
+    }
+
+    #[test]
+    fn test_render() {
+        let hash = SeededRandom::new().random_string(6, "v1\0\0src/components/Button.tsx\0Button.root");
+        assert_eq!(render("[name]-[hash:6]", "root"), format!("root-{hash}"));
+        assert_eq!(render("[name]-[hash]", "root"), format!("root-{hash}"));
+        assert_eq!(
+            render("[dir]_[file]_[name]_[line]", "root"),
+            "components_Button_root_12"
+        );
+        assert_eq!(render("[hash:3]", "root"), hash[..3]);
+        assert_eq!(render("[name]", "$root"), "_root");
+        assert_eq!(render("[line]-[name]", "root"), "_12-root");
+        assert_eq!(render("--[name]", "root"), "_--root");
+    }
+
+    #[test]
+    fn test_hash_is_stable() {
+        // changing these breaks class names of deployed projects, see `HASH_VERSION`
+        assert_eq!(render("[hash:8]", "root"), "nzqyuu3j");
+        let salted = ClassNameTemplate::parse("[hash:8]")
+            .unwrap()
+            .with_hash_salt("docs".to_string())
+            .render(
+                &ClassNameParts {
+                    name: "root",
+                    filepath: "src/components/Button.tsx",
+                    line: 12,
+                    block_key: "Button.root",
+                },
+                &mut SeededRandom::new(),
+            );
+        assert_ne!(salted, "nzqyuu3j");
+    }
+
+    #[test]
+    fn test_project_relative_path() {
+        assert_eq!(
+            project_relative_path("/home/a/app", "/home/a/app/src/Button.tsx"),
+            "src/Button.tsx"
+        );
+        assert_eq!(
+            project_relative_path("C:\\work\\app\\", "C:\\work\\app\\src\\Button.tsx"),
+            "src/Button.tsx"
+        );
+        assert_eq!(
+            project_relative_path("/home/a/app", "/home/a/app2/src/Button.tsx"),
+            "../app2/src/Button.tsx"
+        );
+        assert_eq!(
+            project_relative_path("/repo/apps/web/", "/repo/packages/ui/src/Button.tsx"),
+            "../../packages/ui/src/Button.tsx"
+        );
+        assert_eq!(
+            project_relative_path("/home/a/app", "/repo/node_modules/@acme/ui/dist/index.js"),
+            "@acme/ui/dist/index.js"
+        );
+    }
+
+    #[test]
+    fn test_binding_scopes() {
+        let allocator = oxc_allocator::Allocator::default();
+        let code = "function Card() { const button = css``; return { a: css`` }; }\nconst x = 1;";
+        let program = oxc_parser::Parser::new(&allocator, code, oxc_span::SourceType::ts())
+            .parse()
+            .program;
+        let binding_scopes = BindingScopes::collect(&program);
+
+        let position = |needle: &str| code.find(needle).unwrap() as u32;
+        let template = |start: u32| Span::new(start, start + 1);
+        assert_eq!(binding_scopes.path(template(position("css``;"))), "Card.button");
+        assert_eq!(binding_scopes.path(template(position("css`` }"))), "Card");
+        assert_eq!(binding_scopes.path(template(position("1;"))), "x");
+    }
+
+    #[test]
+    fn test_display_name() {
+        let allocator = oxc_allocator::Allocator::default();
+        let code = r#"
+            const styles = { root: css``, "title-text": css`` };
+            function Card() {
+                return <Box><div class={css``} /></Box>;
+            }
+            class Modal { backdrop = css``; }
+            styles.footer = css``;
+            const [first] = [css``];
+            export default css``;
+        "#;
+        let program = oxc_parser::Parser::new(&allocator, code, oxc_span::SourceType::tsx())
+            .parse()
+            .program;
+        let binding_scopes = BindingScopes::collect(&program);
+
+        let name = |nth: usize| {
+            let start = code.match_indices("css``").nth(nth).unwrap().0 as u32;
+            binding_scopes.display_name(Span::new(start, start + 5))
+        };
+        assert_eq!(name(0).as_deref(), Some("styles-root"));
+        assert_eq!(name(1).as_deref(), Some("styles-title-text"));
+        assert_eq!(name(2).as_deref(), Some("Card-div"));
+        assert_eq!(name(3).as_deref(), Some("Modal-backdrop"));
+        assert_eq!(name(4).as_deref(), Some("styles-footer"));
+        assert_eq!(name(5), None);
+        assert_eq!(name(6), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(ClassNameTemplate::parse("").is_err());
+        assert!(ClassNameTemplate::parse("[name").is_err());
+        assert!(ClassNameTemplate::parse("[component]").is_err());
+        assert!(ClassNameTemplate::parse("[hash:0]").is_err());
+        assert!(ClassNameTemplate::parse("[name].[hash]").is_err());
+        assert_eq!(
+            ClassNameTemplate::default(),
+            ClassNameTemplate::parse("[name]-[hash:6]").unwrap()
+        );
+    }
+}
@@ -1,4 +1,7 @@
 use crate::compiler::evaluate_program;
+use crate::compiler::evaluator::is_affected_by_theme;
+use crate::compiler::module_cache::lookup_module;
+use crate::compiler::module_store::ModuleStore;
 
 pub use crate::compiler::{TransformError, Transformer};
 use crate::*;
@@ -6,19 +9,117 @@ use oxc_allocator::Allocator;
 use oxc_ast::AstBuilder;
 use oxc_parser::{ParseOptions, Parser};
 use oxc_span::SourceType;
-use std::cell::RefCell;
 use std::collections::HashSet;
 use std::rc::Rc;
 use wasm_bindgen_futures::spawn_local;
 
 #[wasm_bindgen]
 impl Transformer {
+    /// `partial_source` is set when the code is only part of the file, e.g. the `<script>` of
+    /// a Svelte component, css blocks used by the rest of the file aren't reported as unused
+    /// then
     pub async fn transform(
         &self,
         code: String,
         filepath: String,
         skip_css_eval: bool,
         import_source: Option<String>,
+        partial_source: Option<bool>,
+    ) -> Result<Option<JsValue>, TransformError> {
+        self.transform_with_store(
+            code,
+            filepath,
+            skip_css_eval,
+            import_source,
+            partial_source.unwrap_or_default(),
+            Default::default(),
+        )
+        .await
+    }
+
+    /// Transforms `{ code, filepath, importSource?, partialSource? }` entries together.
+    /// Dependencies and evaluated values are shared between the files, results keep the input
+    /// order and failures, including malformed entries, are returned in place of the file
+    /// result.
+    pub async fn transform_many(
+        &self,
+        files: js_sys::Array,
+        skip_css_eval: bool,
+    ) -> Result<js_sys::Array, TransformError> {
+        let module_store = Rc::new(ModuleStore::default());
+
+        let transforms = files
+            .iter()
+            .enumerate()
+            .map(|(index, file)| {
+                let entry = batch_entry(index as u32, &file);
+                let module_store = module_store.clone();
+
+                async move {
+                    let (code, filepath, import_source, partial_source) = entry?;
+                    self.transform_with_store(
+                        code,
+                        filepath,
+                        skip_css_eval,
+                        import_source,
+                        partial_source,
+                        module_store,
+                    )
+                    .await
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let results = js_sys::Array::new();
+        for result in futures::future::join_all(transforms).await {
+            results.push(&match result {
+                Ok(result) => result.unwrap_or(JsValue::UNDEFINED),
+                Err(err) => err.into(),
+            });
+        }
+
+        Ok(results)
+    }
+}
+
+/// reads a `transform_many` entry into (code, filepath, import source, partial source)
+fn batch_entry(
+    index: u32,
+    file: &JsValue,
+) -> Result<(String, String, Option<String>, bool), TransformError> {
+    let invalid = |problem: &str| TransformError::InvalidBatchEntry {
+        index,
+        problem: problem.to_string(),
+    };
+    if !file.is_object() {
+        return Err(invalid("is not an object"));
+    }
+    let get = |key: &str| js_sys::Reflect::get(file, &JsValue::from_str(key)).unwrap_or_default();
+
+    let code = get("code")
+        .as_string()
+        .ok_or_else(|| invalid("has no 'code' string"))?;
+    let filepath = get("filepath")
+        .as_string()
+        .ok_or_else(|| invalid("has no 'filepath' string"))?;
+
+    Ok((
+        code,
+        filepath,
+        get("importSource").as_string(),
+        get("partialSource").as_bool().unwrap_or_default(),
+    ))
+}
+
+impl Transformer {
+    async fn transform_with_store(
+        &self,
+        code: String,
+        filepath: String,
+        skip_css_eval: bool,
+        import_source: Option<String>,
+        partial_source: bool,
+        module_store: Rc<ModuleStore>,
     ) -> Result<Option<JsValue>, TransformError> {
         let _self = self.clone();
         let (tx, rx) = futures::channel::oneshot::channel();
@@ -33,7 +134,6 @@ impl Transformer {
         spawn_local(async move {
             let allocator = Allocator::default();
             let ast_builder = AstBuilder::new(&allocator);
-            let temporary_programs = Rc::new(RefCell::new(Default::default()));
 
             let Ok(source_type) = SourceType::from_path(&filepath) else {
                 if _self.atomic {
@@ -49,6 +149,9 @@ impl Transformer {
                 return;
             };
 
+            // edited files have their previously evaluated values dropped
+            lookup_module(&filepath, &code);
+
             let mut ast = Parser::new(&allocator, &code, source_type)
                 .with_options(ParseOptions {
                     parse_regular_expression: true,
@@ -71,20 +174,39 @@ impl Transformer {
                 return;
             }
 
+            // atomic classes are shared between files, so they can't differ per theme
+            let theme_css = if skip_css_eval || _self.atomic {
+                String::new()
+            } else {
+                match _self
+                    .evaluate_themes(&code, &filepath, source_type, &module_store)
+                    .await
+                {
+                    Ok(theme_css) => theme_css,
+                    Err(err) => {
+                        let _ = tx.send(Err(err));
+                        return;
+                    }
+                }
+            };
+
             evaluate_program(
                 &ast_builder,
                 &_self,
                 true,
-                &_self.cwd,
+                &_self.project_root,
                 filepath.clone(),
                 None,
                 &code,
                 &mut ast.program,
                 HashSet::new(),
-                temporary_programs.clone(),
+                module_store,
                 import_source,
                 Some(tx),
                 skip_css_eval,
+                partial_source,
+                None,
+                &theme_css,
             )
             .await;
             
@@ -98,4 +220,65 @@ impl Transformer {
 
         rx.await.unwrap()
     }
+
+    /// Evaluates the file once per theme with the theme's modules replaced. Class names don't
+    /// depend on the evaluated values, so only the CSS differs and is scoped under the theme
+    /// class. Themes whose modules the file doesn't reach are skipped.
+    async fn evaluate_themes(
+        &self,
+        code: &str,
+        filepath: &str,
+        source_type: SourceType,
+        module_store: &Rc<ModuleStore>,
+    ) -> Result<String, TransformError> {
+        let mut theme_css = vec![];
+
+        for theme in &self.themes {
+            // the theme CSS of files not reaching any replaced module is the regular CSS, which
+            // is known from the imports recorded by earlier passes, or by this one below
+            if !is_affected_by_theme(self, module_store, filepath, theme).await {
+                continue;
+            }
+
+            let allocator = Allocator::default();
+            let ast_builder = AstBuilder::new(&allocator);
+            let mut program = Parser::new(&allocator, code, source_type)
+                .with_options(ParseOptions {
+                    parse_regular_expression: true,
+                    ..ParseOptions::default()
+                })
+                .parse()
+                .program;
+
+            let (tx, rx) = futures::channel::oneshot::channel();
+            evaluate_program(
+                &ast_builder,
+                self,
+                true,
+                &self.project_root,
+                filepath.to_string(),
+                None,
+                code,
+                &mut program,
+                HashSet::new(),
+                module_store.clone(),
+                None,
+                Some(tx),
+                false,
+                false,
+                Some(theme),
+                "",
+            )
+            .await;
+
+            if let Some(css) = rx.await.unwrap_or(Ok(None))?.and_then(|css| css.as_string())
+                && !css.is_empty()
+                && is_affected_by_theme(self, module_store, filepath, theme).await
+            {
+                theme_css.push(theme.scope_css(&css, self.wrap_selectors_with_global));
+            }
+        }
+
+        Ok(theme_css.join("\n"))
+    }
 }
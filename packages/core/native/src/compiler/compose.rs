@@ -0,0 +1,30 @@
+use crate::PREFIX;
+use wasm_bindgen::prelude::*;
+
+/// name of the global function `compose(...)` calls inside css templates are rewritten to
+pub(crate) fn compose_function_name() -> String {
+    format!("{PREFIX}_compose")
+}
+
+/// makes block composition available to evaluated programs, it returns the declarations of
+/// the composed css blocks so they are merged into the composing class
+pub(crate) fn register_compose() {
+    let global = js_sys::global();
+    let name = JsValue::from_str(&compose_function_name());
+    if js_sys::Reflect::has(&global, &name).unwrap_or_default() {
+        return;
+    }
+
+    let function = js_sys::Function::new_with_args(
+        "...blocks",
+        "
+        return blocks.map((block) => {
+            if (typeof block?.css !== 'string') {
+                throw new Error('@style-this: compose() expects css blocks');
+            }
+            return block.css;
+        }).join('\\n');
+        ",
+    );
+    js_sys::Reflect::set(&global, &name, &function).unwrap();
+}
@@ -1,6 +1,5 @@
 use super::types::CssVariableIdentifier;
 use oxc_sourcemap::{SourceMap, SourceMapBuilder};
-use oxc_span::Span;
 
 /// Generates a CSS source map from CSS variable identifiers
 pub fn generate_css_sourcemap(
@@ -16,22 +15,19 @@ pub fn generate_css_sourcemap(
     let mut builder = SourceMapBuilder::default();
     let source_id = builder.add_source_and_content(original_filepath, original_source);
 
-    // Track current line and column in generated CSS
-    let mut current_line = 0;
-    let mut current_column = 0;
+    // selectors appear in the same order as the css variables, keep searching from the last match
+    let mut search_offset = 0;
 
     // Parse CSS content to find each rule and map it back to source
     for css_var in css_variables {
         // Find the selector in the generated CSS
-        let selector = if css_var.class_name.starts_with("_Global") {
-            // Global styles don't have a class selector
+        // Global styles don't have a class selector
+        let Some(selector) = css_var.selector() else {
             continue;
-        } else {
-            format!(".{}", css_var.class_name)
         };
 
         // Find where this selector appears in the CSS
-        if let Some(pos) = find_selector_position(css_content, &selector, current_column) {
+        if let Some(pos) = find_selector_position(css_content, &selector, search_offset) {
             // Get line and column from the original source span
             let original_line = get_line_from_offset(original_source, css_var.span.start);
             let original_column = get_column_from_offset(original_source, css_var.span.start);
@@ -46,8 +42,7 @@ pub fn generate_css_sourcemap(
                 None,
             );
 
-            current_line = pos.line;
-            current_column = pos.column;
+            search_offset = pos.offset + selector.len();
         }
     }
 
@@ -57,11 +52,11 @@ pub fn generate_css_sourcemap(
 struct Position {
     line: u32,
     column: u32,
+    offset: usize,
 }
 
 /// Find the position of a selector in CSS content
-fn find_selector_position(css_content: &str, selector: &str, start_offset: u32) -> Option<Position> {
-    let start_byte_offset = start_offset as usize;
+fn find_selector_position(css_content: &str, selector: &str, start_byte_offset: usize) -> Option<Position> {
     let search_content = &css_content[start_byte_offset.min(css_content.len())..];
     
     if let Some(offset) = search_content.find(selector) {
@@ -74,7 +69,11 @@ fn find_selector_position(css_content: &str, selector: &str, start_offset: u32)
             .map(|last_newline| absolute_offset - last_newline - 1)
             .unwrap_or(absolute_offset) as u32;
         
-        Some(Position { line, column })
+        Some(Position {
+            line,
+            column,
+            offset: absolute_offset,
+        })
     } else {
         None
     }
@@ -106,6 +105,7 @@ fn get_column_from_offset(source: &str, offset: u32) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use oxc_span::Span;
 
     #[test]
     fn test_line_column_calculation() {
@@ -115,4 +115,33 @@ mod tests {
         assert_eq!(get_column_from_offset(source, 7), 0);
         assert_eq!(get_column_from_offset(source, 10), 3);
     }
+
+    #[test]
+    fn test_generate_css_sourcemap() {
+        let source = "const a = css`color: red;`;\nconst b = css`color: blue;`;";
+        let css = ".a-abc123 {\ncolor: red;\n}\n.b-def456 {\ncolor: blue;\n}";
+        let css_variables = vec![
+            CssVariableIdentifier::new(
+                "a".to_string(),
+                "a-abc123".to_string(),
+                vec![],
+                Span::new(10, 26),
+            ),
+            CssVariableIdentifier::new(
+                "b".to_string(),
+                "b-def456".to_string(),
+                vec![],
+                Span::new(38, 55),
+            ),
+        ];
+
+        let sourcemap = generate_css_sourcemap(css, &css_variables, "entry.tsx", source).unwrap();
+        let tokens = sourcemap.get_tokens().collect::<Vec<_>>();
+
+        assert_eq!(tokens.len(), 2);
+        assert_eq!((tokens[0].get_dst_line(), tokens[0].get_dst_col()), (0, 0));
+        assert_eq!((tokens[0].get_src_line(), tokens[0].get_src_col()), (0, 10));
+        assert_eq!((tokens[1].get_dst_line(), tokens[1].get_dst_col()), (3, 0));
+        assert_eq!((tokens[1].get_src_line(), tokens[1].get_src_col()), (1, 10));
+    }
 }
@@ -0,0 +1,426 @@
+use super::static_eval::{StaticValue, fold_expression};
+use crate::{PREFIX, ast};
+use oxc_allocator::{Allocator, CloneIn};
+use oxc_ast::AstBuilder;
+use oxc_ast::ast::{CallExpression, Expression, LogicalOperator, Program};
+use oxc_ast_visit::VisitMut;
+use oxc_span::Span;
+use std::collections::{HashMap, HashSet};
+use wasm_bindgen::prelude::*;
+
+/// An argument of `cx(...)` with what's known at compile time folded
+enum CxArgument<'a, 'alloc> {
+    /// class names, empty for skipped values like `false`
+    Static(String),
+    /// `active && "on"` or `active ? "on" : "off"` with both branches known
+    Conditional {
+        test: &'a Expression<'alloc>,
+        consequent: String,
+        alternate: String,
+    },
+    /// only known at runtime, e.g. a class name passed in as a prop
+    Runtime(&'a Expression<'alloc>),
+}
+
+/// compiles the `cx(...)` calls at the given spans into string concatenations
+///
+/// `calls` holds the identifiers of each call's arguments which refer to top-level bindings,
+/// only those are looked up with `resolve`. With `runtime_merge` (atomic mode) anything not
+/// known at compile time stays wrapped in `cx` so later atomic classes win. Calls of only
+/// known strings and css blocks of the file (`atomic_block` gives their variable in the
+/// virtual program) are merged once evaluated instead, they're replaced with a placeholder
+/// string and the returned placeholders map to the virtual program expression merging them.
+pub(crate) fn compile_cx_calls<'alloc>(
+    ast_builder: &AstBuilder<'alloc>,
+    allocator: &'alloc Allocator,
+    program: &mut Program<'alloc>,
+    calls: &HashMap<Span, HashSet<String>>,
+    resolve: &dyn Fn(&str) -> Option<StaticValue>,
+    runtime_merge: bool,
+    atomic_block: &dyn Fn(&str) -> Option<String>,
+) -> Vec<(String, String)> {
+    if calls.is_empty() {
+        return vec![];
+    }
+
+    let mut compiler = CxCompiler {
+        ast_builder,
+        allocator,
+        calls,
+        resolve,
+        runtime_merge,
+        atomic_block,
+        evaluated: vec![],
+    };
+    compiler.visit_program(program);
+    compiler.evaluated
+}
+
+/// name of the global function merging `cx(...)` calls of atomic css blocks in evaluated
+/// programs
+pub(crate) fn atomic_cx_function_name() -> String {
+    format!("{PREFIX}_atomicCx")
+}
+
+/// makes `cx` merging available to evaluated programs, css blocks are expanded to their class
+/// and atomic classes, then classes setting the same property as a later one are dropped
+pub(crate) fn register_atomic_cx() {
+    let global = js_sys::global();
+    let name = JsValue::from_str(&atomic_cx_function_name());
+    if js_sys::Reflect::has(&global, &name).unwrap_or_default() {
+        return;
+    }
+
+    let function = js_sys::Function::new_with_args(
+        "toClassList, toClassKeys, ...classes",
+        "
+        const keys = {};
+        const byKey = new Map();
+        for (let list of classes) {
+            if (typeof list?.css === 'string') {
+                Object.assign(keys, JSON.parse(toClassKeys(list.css)));
+                list = String(list) + ' ' + toClassList(list.css);
+            }
+            if (!list) continue;
+            for (const name of String(list).split(/\\s+/)) {
+                if (!name) continue;
+                const key = keys[name] ?? name;
+                byKey.delete(key);
+                byKey.set(key, name);
+            }
+        }
+        return [...byKey.values()].join(' ');
+        ",
+    );
+    js_sys::Reflect::set(&global, &name, &function).unwrap();
+}
+
+struct CxCompiler<'a, 'alloc> {
+    ast_builder: &'a AstBuilder<'alloc>,
+    allocator: &'alloc Allocator,
+    calls: &'a HashMap<Span, HashSet<String>>,
+    resolve: &'a dyn Fn(&str) -> Option<StaticValue>,
+    runtime_merge: bool,
+    atomic_block: &'a dyn Fn(&str) -> Option<String>,
+    /// placeholders of calls merged once evaluated -> the virtual program expression merging them
+    evaluated: Vec<(String, String)>,
+}
+
+impl<'alloc> CxCompiler<'_, 'alloc> {
+    /// the virtual program arguments of a call of known strings and css blocks, `None` if
+    /// an argument is only known at runtime or there's no css block to merge
+    fn evaluated_arguments(
+        &self,
+        call: &CallExpression<'alloc>,
+        top_level_bindings: &HashSet<String>,
+    ) -> Option<Vec<String>> {
+        let resolve = |name: &str| {
+            top_level_bindings
+                .contains(name)
+                .then(|| (self.resolve)(name))
+                .flatten()
+        };
+
+        let mut has_block = false;
+        let mut arguments = vec![];
+        for argument in &call.arguments {
+            let argument = argument.as_expression()?;
+            if let Expression::Identifier(ident) = argument.without_parentheses()
+                && top_level_bindings.contains(ident.name.as_str())
+                && let Some(block) = (self.atomic_block)(&ident.name)
+            {
+                has_block = true;
+                arguments.push(block);
+                continue;
+            }
+            let classes = static_classes(argument, &resolve)?;
+            arguments.push(serde_json::to_string(&classes).unwrap());
+        }
+        has_block.then_some(arguments)
+    }
+}
+
+impl<'alloc> VisitMut<'alloc> for CxCompiler<'_, 'alloc> {
+    fn visit_expression(&mut self, it: &mut Expression<'alloc>) {
+        oxc_ast_visit::walk_mut::walk_expression(self, it);
+
+        let Expression::CallExpression(call) = it else {
+            return;
+        };
+        let Some(top_level_bindings) = self.calls.get(&call.span) else {
+            return;
+        };
+
+        if self.runtime_merge
+            && let Some(arguments) = self.evaluated_arguments(call, top_level_bindings)
+        {
+            let placeholder = format!("{PREFIX}_cx_{}", self.evaluated.len());
+            self.evaluated.push((
+                placeholder.clone(),
+                format!(
+                    "{}(global.{PREFIX}_cssToAtomicClassList, global.{PREFIX}_cssToAtomicClassKeys, {})",
+                    atomic_cx_function_name(),
+                    arguments.join(", ")
+                ),
+            ));
+            *it = ast::build_string(self.ast_builder, call.span, &placeholder);
+            return;
+        }
+
+        let resolve = |name: &str| {
+            top_level_bindings
+                .contains(name)
+                .then(|| (self.resolve)(name))
+                .flatten()
+        };
+        let Some(class_list) = build_class_list(self.ast_builder, self.allocator, call, &resolve)
+        else {
+            return;
+        };
+
+        *it = match class_list {
+            class_list @ Expression::StringLiteral(_) => class_list,
+            class_list if self.runtime_merge => self.ast_builder.expression_call(
+                call.span,
+                call.callee.clone_in(self.allocator),
+                None as Option<oxc_allocator::Box<_>>,
+                self.ast_builder.vec1(class_list.into()),
+                false,
+            ),
+            class_list => class_list,
+        };
+    }
+}
+
+/// the class string of a `cx(...)` call, `None` for spread arguments
+fn build_class_list<'alloc>(
+    ast_builder: &AstBuilder<'alloc>,
+    allocator: &'alloc Allocator,
+    call: &CallExpression<'alloc>,
+    resolve: &dyn Fn(&str) -> Option<StaticValue>,
+) -> Option<Expression<'alloc>> {
+    let span = call.span;
+    let string = |value: &str| ast::build_string(ast_builder, span, value);
+
+    let mut class_list = None;
+    let mut append = |part: Expression<'alloc>| {
+        class_list = Some(match class_list.take() {
+            Some(class_list) => ast::build_string_concat(ast_builder, span, class_list, part),
+            None => part,
+        });
+    };
+
+    let mut separator = "";
+    for argument in &call.arguments {
+        match classify(argument.as_expression()?, resolve) {
+            CxArgument::Static(classes) => {
+                if classes.is_empty() {
+                    continue;
+                }
+                append(string(&format!("{separator}{classes}")));
+            }
+            CxArgument::Conditional {
+                test,
+                consequent,
+                alternate,
+            } => {
+                if consequent.is_empty() && alternate.is_empty() {
+                    continue;
+                }
+                let branch = |classes: String| match classes.is_empty() {
+                    true => string(""),
+                    false => string(&format!("{separator}{classes}")),
+                };
+                append(ast_builder.expression_conditional(
+                    span,
+                    test.clone_in(allocator),
+                    branch(consequent),
+                    branch(alternate),
+                ));
+            }
+            CxArgument::Runtime(expression) => {
+                if !separator.is_empty() {
+                    append(string(separator));
+                }
+                append(ast_builder.expression_logical(
+                    span,
+                    expression.clone_in(allocator),
+                    LogicalOperator::Or,
+                    string(""),
+                ));
+            }
+        }
+        separator = " ";
+    }
+
+    Some(class_list.unwrap_or_else(|| string("")))
+}
+
+fn classify<'a, 'alloc>(
+    expression: &'a Expression<'alloc>,
+    resolve: &dyn Fn(&str) -> Option<StaticValue>,
+) -> CxArgument<'a, 'alloc> {
+    if let Some(classes) = static_classes(expression, resolve) {
+        return CxArgument::Static(classes);
+    }
+
+    if let Expression::LogicalExpression(logical) = expression.without_parentheses()
+        && logical.operator == LogicalOperator::And
+        && let Some(consequent) = static_classes(&logical.right, resolve)
+    {
+        return CxArgument::Conditional {
+            test: &logical.left,
+            consequent,
+            alternate: String::new(),
+        };
+    }
+
+    if let Expression::ConditionalExpression(conditional) = expression.without_parentheses()
+        && let Some(consequent) = static_classes(&conditional.consequent, resolve)
+        && let Some(alternate) = static_classes(&conditional.alternate, resolve)
+    {
+        return CxArgument::Conditional {
+            test: &conditional.test,
+            consequent,
+            alternate,
+        };
+    }
+
+    CxArgument::Runtime(expression)
+}
+
+/// the whitespace-normalized class names of a value known at compile time, empty for the
+/// falsy values and booleans `cx` skips
+fn static_classes(
+    expression: &Expression,
+    resolve: &dyn Fn(&str) -> Option<StaticValue>,
+) -> Option<String> {
+    let classes = match expression.without_parentheses() {
+        Expression::BooleanLiteral(_) | Expression::NullLiteral(_) => String::new(),
+        Expression::Identifier(identifier) if identifier.name == "undefined" => String::new(),
+        expression => match fold_expression(expression, resolve)? {
+            StaticValue::Number(number) if number == 0.0 || number.is_nan() => String::new(),
+            value @ (StaticValue::String(_) | StaticValue::Number(_)) => value.to_js_string()?,
+            StaticValue::Array(_) | StaticValue::Object(_) => return None,
+        },
+    };
+
+    Some(classes.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_ast::ast::Statement;
+    use oxc_span::GetSpan;
+
+    fn compile(code: &str, runtime_merge: bool) -> String {
+        compile_evaluated(code, runtime_merge).0
+    }
+
+    /// `button` is a css binding known at compile time, `primary` an atomic css block
+    fn compile_evaluated(code: &str, runtime_merge: bool) -> (String, Vec<(String, String)>) {
+        let allocator = Allocator::default();
+        let ast_builder = AstBuilder::new(&allocator);
+        let mut program = oxc_parser::Parser::new(&allocator, code, oxc_span::SourceType::ts())
+            .parse()
+            .program;
+
+        let calls = program
+            .body
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::VariableDeclaration(declaration) => declaration.declarations[0]
+                    .init
+                    .as_ref()
+                    .map(|init| {
+                        let bindings = ["button".to_string(), "primary".to_string()];
+                        (init.span(), HashSet::from(bindings))
+                    }),
+                _ => None,
+            })
+            .collect();
+        let resolve = |name: &str| {
+            (name == "button").then(|| StaticValue::String("button-abc123".to_string()))
+        };
+
+        let atomic_block =
+            |name: &str| (name == "primary").then(|| "__styleThis_primary".to_string());
+
+        let evaluated = compile_cx_calls(
+            &ast_builder,
+            &allocator,
+            &mut program,
+            &calls,
+            &resolve,
+            runtime_merge,
+            &atomic_block,
+        );
+        (oxc_codegen::Codegen::new().build(&program).code, evaluated)
+    }
+
+    #[test]
+    fn test_static_arguments() {
+        assert_eq!(
+            compile(
+                "const a = cx(button, ' large  primary', false, null, undefined, 0);",
+                false
+            ),
+            "const a = \"button-abc123 large primary\";\n"
+        );
+        assert_eq!(compile("const a = cx();", false), "const a = \"\";\n");
+    }
+
+    #[test]
+    fn test_conditional_arguments() {
+        assert_eq!(
+            compile(
+                "const a = cx(button, active && 'active', size ? 'lg' : null, props.className);",
+                false
+            ),
+            "const a = \"button-abc123\" + (active ? \" active\" : \"\") + (size ? \" lg\" : \"\") + \" \" + (props.className || \"\");\n"
+        );
+        assert_eq!(
+            compile("const a = cx(button, ...rest);", false),
+            "const a = cx(button, ...rest);\n"
+        );
+    }
+
+    #[test]
+    fn test_runtime_merge() {
+        assert_eq!(
+            compile("const a = cx('card', active && other);", true),
+            "const a = cx(\"card \" + (active && other || \"\"));\n"
+        );
+        assert_eq!(
+            compile("const a = cx('card', 'active');", true),
+            "const a = \"card active\";\n"
+        );
+    }
+
+    #[test]
+    fn test_atomic_blocks_merged_once_evaluated() {
+        // the atomic classes of `primary` are only known once evaluated, so later ones can
+        // replace earlier classes setting the same property
+        let (code, evaluated) = compile_evaluated("const a = cx(' card', primary, false);", true);
+        assert_eq!(code, "const a = \"__styleThis_cx_0\";\n");
+        assert_eq!(
+            evaluated,
+            vec![(
+                "__styleThis_cx_0".to_string(),
+                "__styleThis_atomicCx(global.__styleThis_cssToAtomicClassList, global.__styleThis_cssToAtomicClassKeys, \"card\", __styleThis_primary, \"\")".to_string()
+            )]
+        );
+
+        // runtime values are merged by `cx` at runtime
+        assert_eq!(
+            compile("const a = cx(primary, props.className);", true),
+            "const a = cx((primary || \"\") + \" \" + (props.className || \"\"));\n"
+        );
+        // without atomic classes the blocks are plain strings
+        assert_eq!(
+            compile_evaluated("const a = cx(primary);", false),
+            ("const a = primary || \"\";\n".to_string(), vec![])
+        );
+    }
+}
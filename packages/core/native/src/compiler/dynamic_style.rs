@@ -0,0 +1,375 @@
+use super::scope_analysis::ScopeAnalysis;
+use crate::ast;
+use oxc_ast::AstBuilder;
+use oxc_ast::ast::{
+    BindingPatternKind, Expression, IdentifierReference, JSXAttributeItem, JSXAttributeValue,
+    JSXExpression, JSXOpeningElement, Program, VariableDeclaration,
+};
+use oxc_ast_visit::{Visit, VisitMut};
+use oxc_semantic::SymbolId;
+use oxc_span::{GetSpan, Span};
+use std::collections::{HashMap, HashSet};
+
+/// Custom properties of css templates using props or local state, which the elements using
+/// the template's class set in their `style`
+#[derive(Default)]
+pub(crate) struct DynamicStyles<'alloc> {
+    /// css bindings -> name of the binding holding their custom properties and its value,
+    /// declared right after the css binding
+    pub bindings: HashMap<SymbolId, (String, Option<Expression<'alloc>>)>,
+    /// span of css templates without a binding -> their custom properties
+    pub inline: HashMap<Span, Expression<'alloc>>,
+}
+
+impl DynamicStyles<'_> {
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty() && self.inline.is_empty()
+    }
+}
+
+/// declares the custom property bindings and sets them in the `style` of JSX elements whose
+/// `className`/`class` uses the templates, returns the span of a use where that's not possible
+pub(crate) fn attach_dynamic_styles<'alloc>(
+    ast_builder: &AstBuilder<'alloc>,
+    program: &mut Program<'alloc>,
+    scopes: &ScopeAnalysis,
+    styles: DynamicStyles<'alloc>,
+) -> Result<(), Span> {
+    if styles.is_empty() {
+        return Ok(());
+    }
+
+    let mut attacher = StyleAttacher {
+        ast_builder,
+        scopes,
+        styles,
+        class_attribute_references: Default::default(),
+        error: None,
+    };
+    attacher.visit_program(program);
+
+    if let Some(span) = attacher.error {
+        return Err(span);
+    }
+    // templates outside of class attributes, e.g. passed to a function
+    match attacher.styles.inline.keys().min() {
+        Some(span) => Err(*span),
+        None => Ok(()),
+    }
+}
+
+struct StyleAttacher<'a, 'alloc> {
+    ast_builder: &'a AstBuilder<'alloc>,
+    scopes: &'a ScopeAnalysis,
+    styles: DynamicStyles<'alloc>,
+    /// references to css bindings whose custom properties are set by their element
+    class_attribute_references: HashSet<Span>,
+    error: Option<Span>,
+}
+
+impl<'alloc> StyleAttacher<'_, 'alloc> {
+    /// the custom properties used by a class attribute, either a reference to the binding
+    /// holding them or the object itself for templates without a binding
+    fn class_styles(&mut self, class_names: &Expression<'alloc>) -> Vec<Expression<'alloc>> {
+        let mut styles = vec![];
+
+        let mut collector = ClassExpressionCollector::default();
+        collector.visit_expression(class_names);
+
+        for ident in collector.identifiers {
+            let Some(symbol) = self.scopes.identifier_reference(&ident).symbol else {
+                continue;
+            };
+            let Some((style_name, _)) = self.styles.bindings.get(&symbol) else {
+                continue;
+            };
+            self.class_attribute_references.insert(ident.span);
+            if styles.iter().any(
+                |style| matches!(style, Expression::Identifier(ident) if ident.name == style_name),
+            ) {
+                continue;
+            }
+            styles.push(ast::build_identifier(self.ast_builder, ident.span, style_name));
+        }
+
+        for span in collector.spans {
+            if let Some(style) = self.styles.inline.remove(&span) {
+                styles.push(style);
+            }
+        }
+
+        styles
+    }
+}
+
+impl<'alloc> VisitMut<'alloc> for StyleAttacher<'_, 'alloc> {
+    fn visit_variable_declaration(&mut self, it: &mut VariableDeclaration<'alloc>) {
+        oxc_ast_visit::walk_mut::walk_variable_declaration(self, it);
+
+        let mut idx = 0;
+        while idx < it.declarations.len() {
+            idx += 1;
+            let BindingPatternKind::BindingIdentifier(ident) = &it.declarations[idx - 1].id.kind
+            else {
+                continue;
+            };
+            let span = ident.span;
+            let Some(symbol) = self.scopes.binding_reference(ident).symbol else {
+                continue;
+            };
+            let Some((style_name, Some(style))) = self
+                .styles
+                .bindings
+                .get_mut(&symbol)
+                .map(|(style_name, style)| (style_name.clone(), style.take()))
+            else {
+                continue;
+            };
+
+            let declarator =
+                ast::build_variable_declarator(self.ast_builder, span, &style_name, style);
+            it.declarations.insert(idx, declarator);
+            idx += 1;
+        }
+    }
+
+    fn visit_jsx_opening_element(&mut self, it: &mut JSXOpeningElement<'alloc>) {
+        let mut styles = vec![];
+        for attribute in &it.attributes {
+            if let JSXAttributeItem::Attribute(attribute) = attribute
+                && (attribute.is_identifier("className") || attribute.is_identifier("class"))
+                && let Some(JSXAttributeValue::ExpressionContainer(container)) = &attribute.value
+                && let Some(class_names) = container.expression.as_expression()
+            {
+                styles.extend(self.class_styles(class_names));
+            }
+        }
+
+        oxc_ast_visit::walk_mut::walk_jsx_opening_element(self, it);
+
+        if styles.is_empty() {
+            return;
+        }
+        let span = it.span;
+
+        let existing_style = it.attributes.iter_mut().find_map(|attribute| match attribute {
+            JSXAttributeItem::Attribute(attribute) if attribute.is_identifier("style") => {
+                Some(attribute)
+            }
+            _ => None,
+        });
+
+        match existing_style {
+            None => {
+                let style = if styles.len() == 1 {
+                    styles.pop().unwrap()
+                } else {
+                    spread_objects(self.ast_builder, span, styles)
+                };
+                it.attributes.push(self.ast_builder.jsx_attribute_item_attribute(
+                    span,
+                    self.ast_builder.jsx_attribute_name_identifier(span, "style"),
+                    Some(self.ast_builder.jsx_attribute_value_expression_container(
+                        span,
+                        JSXExpression::from(style),
+                    )),
+                ));
+            }
+            // the element's own style wins over the custom properties
+            Some(attribute) => {
+                let Some(JSXAttributeValue::ExpressionContainer(container)) = &mut attribute.value
+                else {
+                    // `style="..."` strings can't be merged with an object
+                    self.error.get_or_insert(attribute.span);
+                    return;
+                };
+                let Some(existing) = container.expression.as_expression_mut() else {
+                    return;
+                };
+                let existing =
+                    std::mem::replace(existing, ast::build_undefined(self.ast_builder, span));
+                styles.push(existing);
+                container.expression =
+                    JSXExpression::from(spread_objects(self.ast_builder, span, styles));
+            }
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, it: &mut IdentifierReference<'alloc>) {
+        if self.class_attribute_references.contains(&it.span) {
+            return;
+        }
+        if let Some(symbol) = self.scopes.identifier_reference(it).symbol
+            && self.styles.bindings.contains_key(&symbol)
+        {
+            // the custom properties can't follow the class anywhere else
+            self.error.get_or_insert(it.span);
+        }
+    }
+}
+
+/// `{ ...a, ...b }`
+fn spread_objects<'alloc>(
+    ast_builder: &AstBuilder<'alloc>,
+    span: Span,
+    objects: Vec<Expression<'alloc>>,
+) -> Expression<'alloc> {
+    ast_builder.expression_object(
+        span,
+        ast_builder.vec_from_iter(
+            objects
+                .into_iter()
+                .map(|object| ast_builder.object_property_kind_spread_property(span, object)),
+        ),
+    )
+}
+
+/// identifiers and expression spans of a class attribute value
+#[derive(Default)]
+struct ClassExpressionCollector<'alloc> {
+    identifiers: Vec<IdentifierReference<'alloc>>,
+    spans: Vec<Span>,
+}
+
+impl<'alloc> Visit<'alloc> for ClassExpressionCollector<'alloc> {
+    fn visit_expression(&mut self, it: &Expression<'alloc>) {
+        self.spans.push(it.span());
+        oxc_ast_visit::walk::walk_expression(self, it);
+    }
+
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'alloc>) {
+        self.identifiers.push(it.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+
+    fn attach(code: &str, binding: &str, inline: Option<&str>) -> Result<String, String> {
+        let allocator = Allocator::default();
+        let ast_builder = AstBuilder::new(&allocator);
+        let mut program = oxc_parser::Parser::new(&allocator, code, oxc_span::SourceType::tsx())
+            .parse()
+            .program;
+        let scopes = ScopeAnalysis::build(&program, &|_| false);
+
+        let mut styles = DynamicStyles::default();
+        let style = |span| {
+            ast_builder.expression_object(
+                span,
+                ast_builder.vec1(ast_builder.object_property_kind_object_property(
+                    span,
+                    oxc_ast::ast::PropertyKind::Init,
+                    ast_builder
+                        .expression_string_literal(span, ast_builder.atom("--x"), None)
+                        .into(),
+                    ast::build_identifier(&ast_builder, span, "color"),
+                    false,
+                    false,
+                    false,
+                )),
+            )
+        };
+
+        // the css binding is declared in the first statement of the component
+        let Some(Statement::FunctionDeclaration(function)) = program.body.first() else {
+            panic!("expected a component");
+        };
+        let Some(Statement::VariableDeclaration(declaration)) =
+            function.body.as_ref().unwrap().statements.first()
+        else {
+            panic!("expected a css binding");
+        };
+        for declarator in &declaration.declarations {
+            let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+                continue;
+            };
+            if ident.name == binding {
+                let symbol = scopes.binding_reference(ident).symbol.unwrap();
+                styles.bindings.insert(
+                    symbol,
+                    (format!("{binding}_style"), Some(style(ident.span))),
+                );
+            }
+        }
+        if let Some(inline) = inline {
+            let start = code.find(inline).unwrap() as u32;
+            let span = Span::new(start, start + inline.len() as u32);
+            styles.inline.insert(span, style(span));
+        }
+
+        attach_dynamic_styles(&ast_builder, &mut program, &scopes, styles)
+            .map(|_| oxc_codegen::Codegen::new().build(&program).code)
+            .map_err(|span| code[span.start as usize..span.end as usize].to_string())
+    }
+
+    #[test]
+    fn test_binding_style() {
+        let code = attach(
+            r#"function C(props) { const cls = "cls-a"; return <div className={active ? cls : ""} />; }"#,
+            "cls",
+            None,
+        )
+        .unwrap();
+
+        assert!(code.contains(r#"const cls = "cls-a", cls_style = { "--x": color };"#));
+        assert!(code.contains(r#"<div className={active ? cls : ""} style={cls_style} />"#));
+    }
+
+    #[test]
+    fn test_merges_existing_style() {
+        let code = attach(
+            r#"function C(props) { const cls = "cls-a"; return <div style={{ color: "red" }} class={cls} />; }"#,
+            "cls",
+            None,
+        )
+        .unwrap();
+
+        assert!(code.contains(
+            "style={{\n\t\t...cls_style,\n\t\t...{ color: \"red\" }\n\t}} class={cls}"
+        ));
+    }
+
+    #[test]
+    fn test_inline_style() {
+        let code = attach(
+            r#"function C(props) { const other = 1; return <div className={"b"} />; }"#,
+            "cls",
+            Some(r#""b""#),
+        )
+        .unwrap();
+
+        assert!(code.contains(r#"<div className={"b"} style={{ "--x": color }} />"#));
+    }
+
+    #[test]
+    fn test_use_outside_class_attribute() {
+        assert_eq!(
+            attach(
+                r#"function C(props) { const cls = "cls-a"; return <Child cls={cls} />; }"#,
+                "cls",
+                None,
+            ),
+            Err("cls".to_string())
+        );
+        assert_eq!(
+            attach(
+                r#"function C(props) { const other = 1; return f("b"); }"#,
+                "cls",
+                Some(r#""b""#),
+            ),
+            Err(r#""b""#.to_string())
+        );
+        assert_eq!(
+            attach(
+                r#"function C(props) { const cls = "cls-a"; return <div style="color: red" className={cls} />; }"#,
+                "cls",
+                None,
+            ),
+            Err(r#"style="color: red""#.to_string())
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use thiserror::Error;
 use wasm_bindgen::prelude::*;
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone)]
 pub enum TransformError {
     #[error("failed to parse program: {message}")]
     RawParseFailed {
@@ -22,8 +22,25 @@ pub enum TransformError {
         program: Option<String>,
         cause: JsValue,
     },
+    #[error("transform_many entry {index} {problem}")]
+    InvalidBatchEntry { index: u32, problem: String },
     #[error("failed to read file '{filepath}'")]
     ReadFileError { filepath: String, cause: JsValue },
+    #[error("{message}")]
+    UnsupportedSyntax {
+        message: String,
+        filepath: String,
+        row: usize,
+        column: usize,
+    },
+    #[error("class name '{class_name}' of '{filepath}' is already used by a block in '{other_filepath}', add [hash] to the classNameTemplate option to keep class names unique")]
+    ClassNameCollision {
+        class_name: String,
+        filepath: String,
+        other_filepath: String,
+        row: usize,
+        column: usize,
+    },
     #[error("tried to access dynamic variable '{variable}'")]
     AccessDynamicVariableError {
         variable: String,
@@ -31,6 +48,13 @@ pub enum TransformError {
         row: usize,
         column: usize,
     },
+    #[error("'{variable}' is reassigned where its value can't be determined at build time")]
+    MutatedVariableError {
+        variable: String,
+        filepath: String,
+        row: usize,
+        column: usize,
+    },
 }
 
 impl From<TransformError> for JsValue {
@@ -46,6 +70,7 @@ impl From<TransformError> for JsValue {
             | TransformError::ReadFileError { cause, .. } => {
                 js_sys::Reflect::set(&err, &JsValue::from_str("cause"), cause).unwrap();
             }
+            TransformError::InvalidBatchEntry { .. } => {}
             TransformError::RawParseFailed {
                 filepath,
                 row,
@@ -57,11 +82,29 @@ impl From<TransformError> for JsValue {
                 row,
                 column,
             }
+            | TransformError::UnsupportedSyntax {
+                filepath,
+                row,
+                column,
+                ..
+            }
+            | TransformError::ClassNameCollision {
+                filepath,
+                row,
+                column,
+                ..
+            }
             | TransformError::AccessDynamicVariableError {
                 filepath,
                 row,
                 column,
                 ..
+            }
+            | TransformError::MutatedVariableError {
+                filepath,
+                row,
+                column,
+                ..
             } => {
                 let message = from.to_string();
                 let stack_trace =
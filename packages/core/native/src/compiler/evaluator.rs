@@ -1,19 +1,34 @@
+use super::assets::AssetKind;
 use super::cache::VALUE_CACHE;
+use super::class_name::project_relative_path;
+use super::compose::register_compose;
+use super::cx::register_atomic_cx;
+use super::css_sourcemap::generate_css_sourcemap;
 use super::error::TransformError;
+use super::library_imports::{LibraryRole, StyledFramework};
+use super::module_cache::{
+    VirtualProgram, count_lookup, lookup_module, module_imports, record_evaluated, record_import,
+};
+use super::module_store::{LoadedModule, ModuleStore};
+use super::object_css::register_object_to_css;
+use super::static_eval::collect_static_consts;
+use super::themes::Theme;
 use super::transformer::Transformer;
-use super::types::ExportedJSValue;
+use super::types::{CssBlockKind, CssVariableIdentifier, ExportedJSValue};
+use super::unused_css::{UnusedCssBlock, find_unused_css_blocks, unused_css_warnings};
 use super::visitor::VisitorTransformer;
 use crate::error_mapping;
 use crate::react::react_prepass;
 use crate::solid_js::solid_js_prepass;
 use crate::utils::{self, transpile_ts_to_js};
-use crate::{LIBRARY_CORE_IMPORT_NAME, LIBRARY_CORE_ATOMIC_IMPORT_NAME, LIBRARY_REACT_IMPORT_NAME, LIBRARY_SOLID_JS_IMPORT_NAME, PREFIX};
+use crate::{LIBRARY_CORE_ATOMIC_IMPORT_NAME, PREFIX};
+use futures::future::LocalBoxFuture;
 use futures::lock::Mutex as FutureMutex;
 use indoc::formatdoc;
 use oxc_allocator::{Allocator, CloneIn};
 use oxc_ast::ast::{
     BindingPatternKind, Expression, ImportDeclarationSpecifier, ImportOrExportKind,
-    ModuleExportName, Program, PropertyKey, Statement, VariableDeclarationKind, WithClause,
+    Program, PropertyKey, Statement, VariableDeclarationKind, WithClause,
 };
 use oxc_ast::AstBuilder;
 use oxc_ast_visit::VisitMut;
@@ -21,7 +36,7 @@ use oxc_codegen::{Codegen, CodegenOptions};
 use oxc_parser::{ParseOptions, Parser};
 use oxc_span::SourceType;
 use std::cell::RefCell;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 use std::path::PathBuf;
 use std::rc::Rc;
 use std::str::FromStr;
@@ -41,24 +56,30 @@ pub async fn evaluate_program<'alloc>(
     ast_builder: &'alloc AstBuilder<'alloc>,
     transformer: &Transformer,
     entrypoint: bool,
-    cwd: &str,
+    project_root: &str,
     program_filepath: String,
     importer_filepath: Option<&str>,
     program_code: &str,
     program: &mut Program<'alloc>,
     mut referenced_idents: HashSet<String>,
-    temporary_programs: Rc<RefCell<HashMap<String, String>>>,
+    module_store: Rc<ModuleStore>,
     import_source: Option<String>,
-    tx: Option<futures::channel::oneshot::Sender<Result<Option<JsValue>, TransformError>>>,
+    mut tx: Option<futures::channel::oneshot::Sender<Result<Option<JsValue>, TransformError>>>,
     skip_css_eval: bool,
-) {
+    partial_source: bool,
+    theme: Option<&Theme>,
+    theme_css: &str,
+) -> Option<VirtualProgram> {
     let allocator = &ast_builder.allocator;
 
+    // values evaluated with a theme's modules are kept apart from the regular ones
+    let cache_key = Theme::cache_key(theme, &program_filepath);
+
     // keep values until end of function
     let value_cache_guard = VALUE_CACHE.with(|cache| {
         cache
             .borrow_mut()
-            .entry(program_filepath.clone())
+            .entry(cache_key.clone())
             .or_insert_with(|| Rc::new(FutureMutex::new(HashSet::new())))
             .clone()
     });
@@ -66,21 +87,28 @@ pub async fn evaluate_program<'alloc>(
     let mut value_cache = value_cache_guard.lock().await;
 
     referenced_idents.retain(|ident| !value_cache.contains(ident));
+    // what a reused virtual program of a dependency evaluates
+    let mut requested_idents = referenced_idents.iter().cloned().collect::<Vec<_>>();
+    requested_idents.sort();
 
     if !entrypoint && referenced_idents.is_empty() {
         if let Some(tx) = tx {
             let _ = tx.send(Ok(None));
         }
-        return;
+        return None;
     }
 
     // find "css" import or quit early if entrypoint
     let mut return_early = entrypoint;
-    let mut solid_prepass = false;
-    let mut react_prepass_enabled = false;
-    let mut style_function_name = None;
-    let mut css_function_name = None;
-    let mut extra_class_function_name = None;
+    let mut style_function_names = HashSet::new();
+    let mut css_function_names = HashSet::new();
+    let mut extra_class_function_names = HashSet::new();
+    let mut keyframes_function_names = HashSet::new();
+    let mut global_css_function_names = HashSet::new();
+    let mut create_tokens_function_names = HashSet::new();
+    let mut compose_function_names = HashSet::new();
+    let mut cx_function_names = HashSet::new();
+    let mut styled = None;
 
     for stmt in &program.body {
         let Statement::ImportDeclaration(import_decl) = stmt else {
@@ -91,51 +119,52 @@ pub async fn evaluate_program<'alloc>(
             continue;
         };
 
+        let Some(library) = transformer
+            .library_imports
+            .get(import_decl.source.value.as_str())
+        else {
+            continue;
+        };
+
         for specifier in specifiers.iter() {
-            // TODO move loop 1 lvl up for perf
-            match import_decl.source.value.as_str() {
-                LIBRARY_CORE_IMPORT_NAME => {
-                    match specifier {
-                        oxc_ast::ast::ImportDeclarationSpecifier::ImportSpecifier(spec)
-                            if spec.imported.name() == "css" =>
-                        {
-                            css_function_name = Some(spec.local.name.to_string());
-                        }
-                        oxc_ast::ast::ImportDeclarationSpecifier::ImportSpecifier(spec)
-                            if spec.imported.name() == "style" =>
-                        {
-                            style_function_name = Some(spec.local.name.to_string());
-                        }
-                        oxc_ast::ast::ImportDeclarationSpecifier::ImportSpecifier(spec)
-                            if spec.imported.name() == "extraClass" =>
-                        {
-                            extra_class_function_name = Some(spec.local.name.to_string());
-                        }
-                        _ => {
-                            continue;
-                        }
+            // (name used in this file, role)
+            let names = match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(spec) => {
+                    let Some(role) = library.role(&spec.imported.name()) else {
+                        continue;
                     };
-                    return_early = false;
+                    vec![(spec.local.name.to_string(), role)]
                 }
-                LIBRARY_SOLID_JS_IMPORT_NAME => {
-                    solid_prepass = true;
-                    if let oxc_ast::ast::ImportDeclarationSpecifier::ImportSpecifier(spec) =
-                        specifier
-                        && spec.local.name == "styled"
-                    {
-                        return_early = false;
-                    }
+                // `import * as st from "@style-this/core"` -> `st.css`...``
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(_)
+                | ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => {
+                    let namespace = get_import_local_name(specifier);
+                    library
+                        .exports()
+                        .map(|(export_name, role)| (format!("{namespace}.{export_name}"), role))
+                        .collect()
                 }
-                LIBRARY_REACT_IMPORT_NAME => {
-                    react_prepass_enabled = true;
-                    if let oxc_ast::ast::ImportDeclarationSpecifier::ImportSpecifier(spec) =
-                        specifier
-                        && spec.local.name == "styled"
-                    {
-                        return_early = false;
+            };
+
+            for (name, role) in names {
+                return_early = false;
+                let function_names = match role {
+                    LibraryRole::Css => &mut css_function_names,
+                    LibraryRole::Style => &mut style_function_names,
+                    LibraryRole::ExtraClass => &mut extra_class_function_names,
+                    LibraryRole::Keyframes => &mut keyframes_function_names,
+                    LibraryRole::GlobalCss => &mut global_css_function_names,
+                    LibraryRole::CreateTokens => &mut create_tokens_function_names,
+                    LibraryRole::Compose => &mut compose_function_names,
+                    LibraryRole::Cx => &mut cx_function_names,
+                    LibraryRole::Styled(framework) => {
+                        styled = Some((name, framework));
+                        continue;
                     }
-                }
-                _ => continue,
+                };
+                // a role can be imported under several names, e.g. from a design system and
+                // from the core package, or through a namespace and a named import
+                function_names.insert(name);
             }
         }
     }
@@ -145,19 +174,62 @@ pub async fn evaluate_program<'alloc>(
         if let Some(tx) = tx {
             let _ = tx.send(Ok(None));
         }
-        return;
+        return None;
     }
 
-    if solid_prepass {
-        solid_js_prepass(ast_builder, program_filepath.clone(), program, false);
-    }
+    // only css blocks written in the entrypoint itself are reported, not ones from `styled`,
+    // and only if the code is the whole file, markup outside of it can use the blocks
+    let unused_css_blocks = if entrypoint && theme.is_none() && !partial_source {
+        find_unused_css_blocks(program, &css_function_names)
+    } else {
+        vec![]
+    };
 
-    if react_prepass_enabled {
-        react_prepass(ast_builder, program_filepath.clone(), program, false);
+    // React wants style objects, files using its `styled` get them without the option
+    let style_objects = transformer.style_objects
+        || matches!(styled, Some((_, StyledFramework::React)));
+
+    if let Some((styled_name, framework)) = &styled {
+        // styled components are rewritten into css templates, which need a tag even when
+        // `css` itself isn't imported
+        let css_name = match css_function_names.iter().next() {
+            Some(css_name) => css_name.clone(),
+            None => {
+                let css_name = format!("{PREFIX}_css");
+                css_function_names.insert(css_name.clone());
+                css_name
+            }
+        };
+
+        match framework {
+            StyledFramework::Solid => solid_js_prepass(
+                ast_builder,
+                project_relative_path(project_root, &program_filepath),
+                program,
+                false,
+                styled_name,
+                &css_name,
+            ),
+            StyledFramework::React => react_prepass(
+                ast_builder,
+                project_relative_path(project_root, &program_filepath),
+                program,
+                false,
+                styled_name,
+                &css_name,
+            ),
+        }
     }
 
     let cache_ref = &transformer.value_cache_ref;
-    let store = format!("global.{cache_ref}[\"{program_filepath}\"]");
+    let store = format!("global.{cache_ref}[\"{cache_key}\"]");
+
+    // only entrypoint CSS can skip JS evaluation, dependencies are evaluated for their values
+    let static_consts = if entrypoint && !transformer.atomic {
+        collect_static_consts(program)
+    } else {
+        Default::default()
+    };
 
     // transform all css`...` expresisons into classname strings
     let mut css_transformer = VisitorTransformer::new(
@@ -166,30 +238,53 @@ pub async fn evaluate_program<'alloc>(
         entrypoint,
         &store,
         referenced_idents.clone(),
-        cwd,
+        project_root,
         &program_filepath,
         program_code,
         &mut value_cache,
-        css_function_name,
-        style_function_name,
-        extra_class_function_name,
+        static_consts,
+        css_function_names,
+        style_function_names,
+        extra_class_function_names,
+        keyframes_function_names,
+        global_css_function_names,
+        create_tokens_function_names,
+        compose_function_names,
+        cx_function_names,
+        &transformer.class_name_template,
         transformer.atomic,
+        style_objects,
     );
     css_transformer.visit_program(program);
     if let Some(error) = css_transformer.error {
         if let Some(tx) = tx {
             let _ = tx.send(Err(error));
         }
-        return;
+        return None;
     }
     let (
-        css_variable_identifiers,
+        mut css_variable_identifiers,
         referenced_idents,
         mut namespace_imports,
         exported_idents,
         tmp_program,
+        evaluated_class_lists,
     ) = css_transformer.finish();
 
+    if transformer.remove_unused_css && !unused_css_blocks.is_empty() {
+        css_variable_identifiers.retain(|css_var| {
+            css_var.kind != CssBlockKind::Class
+                || css_var.is_global()
+                || !unused_css_blocks.iter().any(|block| block.span == css_var.span)
+        });
+    }
+
+    // theme passes only emit class blocks, keyframes and global CSS can't be scoped to a class
+    if theme.is_some() {
+        css_variable_identifiers
+            .retain(|css_var| css_var.kind == CssBlockKind::Class && !css_var.is_global());
+    }
+
     // Transform @style-this/core/atomic imports into virtual module imports
     if transformer.atomic {
         let mut indices_to_replace = vec![];
@@ -223,53 +318,55 @@ pub async fn evaluate_program<'alloc>(
 
     // In atomic mode, add import for .style-this.js module if there are CSS variables
     // This needs to happen BEFORE codegen for entrypoints
-    if transformer.atomic && !css_variable_identifiers.is_empty() {
-        if let Some(import_source) = &import_source {
-            // Remove the .css extension and add .style-this.js
-            let base_import = if let Some(stripped) = import_source.strip_suffix(&format!(".{}", transformer.css_extension)) {
-                stripped
-            } else {
-                import_source.as_str()
-            };
-            let style_this_import_source = format!("{}.style-this.js", base_import);
-            
-            // Create: import * as _styleThisClasses from "virtual:style-this:file.style-this.js"
-            use oxc_ast::ast::ImportNamespaceSpecifier;
-            
-            let namespace_specifier = ast_builder.alloc(ImportNamespaceSpecifier {
-                span: program.span,
-                local: ast_builder.binding_identifier(
-                    program.span,
-                    ast_builder.atom("_styleThisClasses")
-                ),
-            });
-            
-            let specifiers = ast_builder.vec1(ImportDeclarationSpecifier::ImportNamespaceSpecifier(namespace_specifier));
-            
-            let style_this_import = ast_builder
-                .alloc_import_declaration(
-                    program.span,
-                    Some(specifiers),
-                    ast_builder.string_literal(program.span, ast_builder.atom(&style_this_import_source), None),
-                    None,
-                    None::<oxc_allocator::Box<WithClause>>,
-                    ImportOrExportKind::Value,
-                );
+    if transformer.atomic
+        && !css_variable_identifiers.is_empty()
+        && let Some(import_source) = &import_source
+    {
+        // Remove the .css extension and add .style-this.js
+        let base_import = if let Some(stripped) = import_source.strip_suffix(&format!(".{}", transformer.css_extension)) {
+            stripped
+        } else {
+            import_source.as_str()
+        };
+        let style_this_import_source = format!("{}.style-this.js", base_import);
+        
+        // Create: import * as _styleThisClasses from "virtual:style-this:file.style-this.js"
+        use oxc_ast::ast::ImportNamespaceSpecifier;
+        
+        let namespace_specifier = ast_builder.alloc(ImportNamespaceSpecifier {
+            span: program.span,
+            local: ast_builder.binding_identifier(
+                program.span,
+                ast_builder.atom("_styleThisClasses")
+            ),
+        });
+        
+        let specifiers = ast_builder.vec1(ImportDeclarationSpecifier::ImportNamespaceSpecifier(namespace_specifier));
+        
+        let style_this_import = ast_builder
+            .alloc_import_declaration(
+                program.span,
+                Some(specifiers),
+                ast_builder.string_literal(program.span, ast_builder.atom(&style_this_import_source), None),
+                None,
+                None::<oxc_allocator::Box<WithClause>>,
+                ImportOrExportKind::Value,
+            );
 
-            let insert_pos = program
-                .body
-                .iter()
-                .position(|stmt| !matches!(stmt, Statement::ImportDeclaration(_)))
-                .unwrap_or(0);
+        let insert_pos = program
+            .body
+            .iter()
+            .position(|stmt| !matches!(stmt, Statement::ImportDeclaration(_)))
+            .unwrap_or(0);
 
-            program
-                .body
-                .insert(insert_pos, Statement::ImportDeclaration(style_this_import));
-        }
+        program
+            .body
+            .insert(insert_pos, Statement::ImportDeclaration(style_this_import));
     }
 
-    // new entrypoint handling
-    if entrypoint {
+    // new entrypoint handling, theme passes only return their CSS
+    let mut transform_result = None;
+    if entrypoint && theme.is_none() {
         // add import to virtual css
         if let Some(import_source) = &import_source {
             let import_declaration = ast_builder
@@ -293,36 +390,81 @@ pub async fn evaluate_program<'alloc>(
                 .insert(insert_pos, Statement::ImportDeclaration(import_declaration));
         }
 
-        let options = CodegenOptions {
-            source_map_path: Some(PathBuf::from_str(&program_filepath).unwrap()),
-            ..Default::default()
-        };
-        let output_js = Codegen::new().with_options(options).build(&program);
-
-        let result = js_sys::Object::new();
-        js_sys::Reflect::set(
-            &result,
-            &JsValue::from_str("code"),
-            &JsValue::from_str(&format!("// @ts-nocheck\n{}", output_js.code)),
-        )
-        .unwrap();
-        js_sys::Reflect::set(
-            &result,
-            &JsValue::from_str("sourcemap"),
-            &JsValue::from_str(&output_js.map.unwrap().to_json_string()),
-        )
-        .unwrap();
+        // class lists with evaluated extra classes are only generated once evaluated
+        if evaluated_class_lists.is_empty() {
+            let result = output_result(
+                transformer,
+                program,
+                &program_filepath,
+                program_code,
+                &unused_css_blocks,
+            );
+            if transformer.return_css {
+                // the result is completed and sent once the CSS is evaluated
+                transform_result = Some(result);
+            } else if let Some(tx) = tx.take() {
+                let _ = tx.send(Ok(Some(result.into())));
+            }
+        }
+    }
 
+    if skip_css_eval && evaluated_class_lists.is_empty() {
         if let Some(tx) = tx {
-            let _ = tx.send(Ok(Some(result.into())));
+            let _ = tx.send(Ok(transform_result.map(Into::into)));
         }
+        return None;
     }
 
-    if skip_css_eval {
-        return;
+    // every template was folded at compile time, the CSS can be emitted without evaluation
+    if entrypoint
+        && !skip_css_eval
+        && evaluated_class_lists.is_empty()
+        && !transformer.atomic
+        && !css_variable_identifiers.is_empty()
+        && let Some(css) = static_css(transformer, &css_variable_identifiers)
+    {
+        let css = if theme_css.is_empty() {
+            css
+        } else {
+            format!("{css}\n{theme_css}")
+        };
+
+        if theme.is_some() {
+            if let Some(tx) = tx {
+                let _ = tx.send(Ok(Some(JsValue::from_str(&css))));
+            }
+        } else if let Some(result) = transform_result {
+            let evaluated = js_sys::Object::new();
+            js_sys::Reflect::set(&evaluated, &JsValue::from_str("css"), &JsValue::from_str(&css))
+                .unwrap();
+            set_evaluated_css(
+                &result,
+                &evaluated,
+                &css_variable_identifiers,
+                &program_filepath,
+                program_code,
+            );
+            if let Some(tx) = tx {
+                let _ = tx.send(Ok(Some(result.into())));
+            }
+        } else {
+            let css_file_store_ref = &transformer.css_file_store_ref;
+            let css_filepath = format!("'{program_filepath}.{}'", transformer.css_extension);
+            let sourcemap_data = css_sourcemap_data(&css_variable_identifiers);
+            let css = ExportedJSValue::new(JsValue::from_str(&css));
+            js_sys::eval(&format!(
+                "
+                {css_file_store_ref}.get({css_filepath}).resolve({css}, [{sourcemap_data}], {css_filepath});
+                "
+            ))
+            .unwrap();
+        }
+        return None;
     }
 
     let eval_program = Rc::new(RefCell::new(tmp_program));
+    // dependencies the virtual program reads, evaluated again before it when it's reused
+    let imports = Rc::new(RefCell::new(vec![]));
 
     let mut futures = vec![];
 
@@ -343,9 +485,16 @@ pub async fn evaluate_program<'alloc>(
             continue;
         };
 
+        let library = transformer.library_imports.get(&remote_module_id);
+
+        // members of library namespaces compiled away (`ui.css`) aren't needed, anything else
+        // the library exports (`ui.tokens`) is a regular dependency
         let mut remote_referenced_idents = namespace_imports
             .remove(&remote_module_id)
             .unwrap_or_default();
+        if let Some(library) = library {
+            remote_referenced_idents.retain(|name| library.role(name).is_none());
+        }
 
         let any_ident_referenced = specifiers.iter().any(|specifier| {
             let local_name = get_import_local_name(specifier);
@@ -369,24 +518,26 @@ pub async fn evaluate_program<'alloc>(
             let program_filepath = program_filepath.clone();
             let specifiers = specifiers.clone_in(allocator);
             let referenced_idents = referenced_idents.clone();
-            let temporary_programs = temporary_programs.clone();
+            let module_store = module_store.clone();
+            let imports = imports.clone();
 
             std::boxed::Box::pin(async move {
-                let (remote_filepath, code) = transformer
-                    .load_file(&remote_module_id, &program_filepath)
-                    .await
-                    .unwrap();
+                let remote_module =
+                    load_import(transformer, &module_store, &remote_module_id, &program_filepath, theme)
+                        .await?;
+                let remote_filepath = remote_module.filepath.clone();
+                let remote_cache_key = Theme::cache_key(theme, &remote_filepath);
+                let code = remote_module.code.clone();
 
                 for specifier in specifiers.iter() {
-                    // ignore `css` imports from us
-                    if import_declaration.source.value == LIBRARY_CORE_IMPORT_NAME
+                    // ignore the style-this APIs of libraries except `css`, other exports of
+                    // the module are regular dependencies
+                    if let Some(library) = library
                         && let ImportDeclarationSpecifier::ImportSpecifier(import_specifier) =
                             specifier
-                        && !matches!(
-                            &import_specifier.imported,
-                            ModuleExportName::IdentifierName(identifier_name)
-                            if identifier_name.name == "css"
-                        )
+                        && library
+                            .role(&import_specifier.imported.name())
+                            .is_some_and(|role| role != LibraryRole::Css)
                     {
                         continue;
                     }
@@ -563,9 +714,9 @@ pub async fn evaluate_program<'alloc>(
                         };
 
                     let cache_source = if let Some(remote_name) = &remote_name {
-                        format!("{cache_ref}[\"{remote_filepath}\"][\"{remote_name}\"]")
+                        format!("{cache_ref}[\"{remote_cache_key}\"][\"{remote_name}\"]")
                     } else {
-                        format!("{cache_ref}[\"{remote_filepath}\"]")
+                        format!("{cache_ref}[\"{remote_cache_key}\"]")
                     };
 
                     // add new variable declaration to our tmp program
@@ -611,53 +762,22 @@ pub async fn evaluate_program<'alloc>(
                     return Ok(());
                 }
 
-                let source_type = SourceType::from_path(&remote_filepath)
-                    .map_err(|_| TransformError::UknownExtension {
-                        filepath: remote_filepath.clone(),
-                        row: 1,
-                        column: 1,
-                    })
-                    .unwrap();
-
-                let allocator = Allocator::default();
-                let ast_builder = AstBuilder::new(&allocator);
-
-                let ast = Parser::new(&allocator, &code, source_type)
-                    .with_options(ParseOptions {
-                        parse_regular_expression: true,
-                        ..ParseOptions::default()
-                    })
-                    .parse();
-
-                if ast.panicked {
-                    return Err(TransformError::RawParseFailed {
-                        filepath: remote_filepath,
-                        message: ast.errors.first().unwrap().message.to_string(),
-                        row: 1,
-                        column: 1,
-                    });
-                }
-
-                let mut remote_program = ast.program;
+                imports
+                    .borrow_mut()
+                    .push((remote_module_id.clone(), remote_referenced_idents.clone()));
 
-                evaluate_program(
-                    &ast_builder,
+                evaluate_dependency(
                     transformer,
-                    false,
-                    cwd,
-                    remote_filepath,
-                    Some(&program_filepath),
-                    &code,
-                    &mut remote_program,
+                    project_root,
+                    module_store,
+                    &remote_module_id,
+                    &remote_module,
+                    &program_filepath,
                     remote_referenced_idents,
-                    temporary_programs,
-                    None,
-                    None,
                     skip_css_eval,
+                    theme,
                 )
-                .await;
-
-                Ok(())
+                .await
             })
         };
         futures.push(future);
@@ -666,18 +786,25 @@ pub async fn evaluate_program<'alloc>(
     let css_file_store_ref = &transformer.css_file_store_ref;
     let value_cache_ref = &transformer.value_cache_ref;
     let css_filepath = format!("'{program_filepath}.{}'", transformer.css_extension);
-    let css_filepath_unquoted = format!("{program_filepath}.{}", transformer.css_extension);
-
 
-    if let Err(err) = futures::future::try_join_all(futures).await {
+    // errors go to the caller if it's still waiting, otherwise into the CSS cache entry
+    let report_error = |tx: Option<futures::channel::oneshot::Sender<_>>, err: TransformError| {
+        if let Some(tx) = tx {
+            let _ = tx.send(Err(err));
+            return;
+        }
         let err = ExportedJSValue::new(err.into());
         js_sys::eval(&format!(
             "
-            {css_file_store_ref}.get({css_filepath}).reject({err});
+            {css_file_store_ref}.get({css_filepath}).resolve({err});
             "
         ))
         .unwrap();
-        return;
+    };
+
+    if let Err(err) = futures::future::try_join_all(futures).await {
+        report_error(tx, err);
+        return None;
     }
 
     transpile_ts_to_js(allocator, &mut eval_program.borrow_mut());
@@ -698,10 +825,6 @@ pub async fn evaluate_program<'alloc>(
 
     // For atomic mode, prepend variable initializations so .css properties can be assigned
     if transformer.atomic && !css_variable_identifiers.is_empty() {
-        let (global_vars, atomic_vars): (Vec<_>, Vec<_>) = css_variable_identifiers
-            .iter()
-            .partition(|css_var| css_var.class_name.starts_with("_Global"));
-        
         let var_initializations = css_variable_identifiers
             .iter()
             .map(|css_var| {
@@ -718,6 +841,8 @@ pub async fn evaluate_program<'alloc>(
 
     // js_sys::eval(&format!("console.log('program', '{program_path}')",)).unwrap();
 
+    let evaluated_idents = exported_idents.clone();
+
     // we append all exported idents we evaluated to the cache
     if !exported_idents.is_empty() {
         value_cache.extend(exported_idents.iter().cloned());
@@ -730,7 +855,24 @@ pub async fn evaluate_program<'alloc>(
         eval_program_js.push_str(&format!("\n{store} = {{...({store} ?? {{}}), {idents}}};"));
     }
 
-    let has_css = !css_variable_identifiers.is_empty();
+    // CSS already evaluated before is only evaluated again for the class lists
+    let has_css = !css_variable_identifiers.is_empty() && !skip_css_eval;
+
+    let class_lists_field = if evaluated_class_lists.is_empty() || !entrypoint || theme.is_some() {
+        String::new()
+    } else {
+        let class_lists = evaluated_class_lists
+            .iter()
+            .map(|(placeholder, value)| format!("'{placeholder}': {value}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        eval_program_js.push_str(&format!("\nconst {PREFIX}_classLists = {{ {class_lists} }};"));
+        format!(", classLists: {PREFIX}_classLists")
+    };
+
+    // the CSS of the theme passes is appended to the evaluated CSS
+    let theme_css =
+        (!theme_css.is_empty()).then(|| ExportedJSValue::new(JsValue::from_str(theme_css)));
     
     // Track the virtual CSS file if in atomic mode and we have CSS to generate
     let virtual_css_filepath = if transformer.atomic && entrypoint && has_css {
@@ -746,25 +888,12 @@ pub async fn evaluate_program<'alloc>(
         if transformer.atomic {
             // Atomic mode: parse CSS into atomic classes
             // Build source map metadata for JavaScript
-            let sourcemap_data = css_variable_identifiers
-                .iter()
-                .map(|css_var| {
-                    let line = css_var.span.start;
-                    let end_line = css_var.span.end;
-                    format!(
-                        "{{className:'{}',start:{},end:{}}}",
-                        css_var.class_name.replace('\'', "\\'"),
-                        line,
-                        end_line
-                    )
-                })
-                .collect::<Vec<_>>()
-                .join(",");
+            let sourcemap_data = css_sourcemap_data(&css_variable_identifiers);
 
-            // Separate global styles from atomic-eligible styles
+            // Separate global styles and keyframes from atomic-eligible styles
             let (global_vars, atomic_vars): (Vec<_>, Vec<_>) = css_variable_identifiers
                 .iter()
-                .partition(|css_var| css_var.class_name.starts_with("_Global"));
+                .partition(|css_var| css_var.kind != CssBlockKind::Class || css_var.is_global());
 
             // For atomic mode, we need to:
             // 1. Variables are initialized as objects (done above before eval_program_js)
@@ -773,6 +902,12 @@ pub async fn evaluate_program<'alloc>(
             // 4. Generate the .style-this.js module with those lists
             
             // Register all atomic classes (this happens after eval_program_js runs)
+            let resolve_per_file_css = if transformer.return_css {
+                String::new()
+            } else {
+                format!("global.{css_file_store_ref}.get({css_filepath}).resolve(perFileCss, cssSourcemapData, {css_filepath});")
+            };
+
             let css_transformations = atomic_vars
                 .iter()
                 .map(|css_var| {
@@ -781,7 +916,8 @@ pub async fn evaluate_program<'alloc>(
                     // Also store the non-atomic CSS for the per-file CSS
                     // Add error handling for undefined CSS
                     format!(
-                        "if (!{}.css) {{ console.error('[atomic] {}.css is undefined'); {}.css = ''; }}\nconst _{}_atomic = cssToAtomicClassList({}.css);",
+                        "if (!{}.css) {{ console.error('[atomic] {}.css is undefined'); {}.css = ''; }}\nconst _{}_atomic = cssToAtomicClassList({}.css);\nObject.assign(atomicClassKeys, JSON.parse(cssToAtomicClassKeys({}.css)));",
+                        css_var.variable_name,
                         css_var.variable_name,
                         css_var.variable_name,
                         css_var.variable_name,
@@ -812,8 +948,10 @@ pub async fn evaluate_program<'alloc>(
                 .collect::<Vec<_>>();
             
             // Add global vars to exports (they don't have atomic classes, just the variable name)
+            // keyframes and `globalCss` are replaced in place, so they aren't exported
             let global_exports = global_vars
                 .iter()
+                .filter(|css_var| css_var.kind == CssBlockKind::Class)
                 .map(|css_var| {
                     let base_name = css_var.variable_name.strip_prefix(&format!("{}_", PREFIX))
                         .unwrap_or(&css_var.variable_name);
@@ -828,18 +966,16 @@ pub async fn evaluate_program<'alloc>(
                 })
                 .collect::<Vec<_>>();
             
+            // lets `cx` in atomic mode drop earlier classes setting the same property
+            let atomic_class_keys = (!atomic_exports.is_empty())
+                .then(|| "'Object.assign(globalThis.__styleThisAtomicClassKeys ??= {}, ' + JSON.stringify(atomicClassKeys) + ');'".to_string());
+
             let style_this_exports = [atomic_exports, global_exports]
                 .concat()
-                .join(" + '\\n' + ");
-
-            // Global styles keep their original format
-            let global_css = global_vars
-                .iter()
-                .map(|css_var| {
-                    format!("`${{{}.css}}\n`", css_var.variable_name)
-                })
+                .into_iter()
+                .chain(atomic_class_keys)
                 .collect::<Vec<_>>()
-                .join(",\n");
+                .join(" + '\\n' + ");
 
             // Generate CSS blocks with full content (same as non-atomic mode)
             // This preserves media queries, nested selectors, etc.
@@ -864,7 +1000,8 @@ pub async fn evaluate_program<'alloc>(
             let global_css_blocks = global_vars
                 .iter()
                 .map(|css_var| {
-                    format!("`${{{}.css}}\n`", css_var.variable_name)
+                    let css = format!("${{{}.css}}", css_var.variable_name);
+                    format!("`{}`", css_var.css_block(&css, transformer.wrap_selectors_with_global))
                 })
                 .collect::<Vec<_>>()
                 .join(",\n");
@@ -880,7 +1017,14 @@ pub async fn evaluate_program<'alloc>(
                 format!("[{global_css_blocks}, {atomic_css_blocks}].join('\\n')")
             };
 
-            let style_this_module_code = if !style_this_exports.is_empty() {
+            let style_this_module_code = if transformer.return_css {
+                let style_this_module = if style_this_exports.is_empty() {
+                    "''"
+                } else {
+                    style_this_exports.as_str()
+                };
+                format!("return {{ css: perFileCss, atomicModule: {style_this_module}{class_lists_field} }};")
+            } else if !style_this_exports.is_empty() {
                 format!("const styleThisModule = {};\nglobal.{}.get('{}.style-this.js').resolve(styleThisModule);",
                     style_this_exports,
                     css_file_store_ref,
@@ -899,12 +1043,17 @@ pub async fn evaluate_program<'alloc>(
                 // Import atomic CSS helpers from wasm
                 const cssToAtomicClassList = global.__styleThis_cssToAtomicClassList;
                 const extractNonAtomicCss = global.__styleThis_extractNonAtomicCss;
+                const cssToAtomicClassKeys = global.__styleThis_cssToAtomicClassKeys;
+                const atomicClassKeys = {{}};
                 if (!cssToAtomicClassList) {{
                     throw new Error('cssToAtomicClassList not found on global. Available: ' + Object.keys(global).filter(k => k.includes('styleThis')).join(', '));
                 }}
                 if (!extractNonAtomicCss) {{
                     throw new Error('extractNonAtomicCss not found on global. Available: ' + Object.keys(global).filter(k => k.includes('styleThis')).join(', '));
                 }}
+                if (!cssToAtomicClassKeys) {{
+                    throw new Error('cssToAtomicClassKeys not found on global. Available: ' + Object.keys(global).filter(k => k.includes('styleThis')).join(', '));
+                }}
                 
                 // Convert CSS to atomic class lists
                 {css_transformations}
@@ -914,7 +1063,7 @@ pub async fn evaluate_program<'alloc>(
                 // In atomic mode, resolve per-file CSS with marker classes for sourcemaps
                 // The actual atomic CSS goes into the .atomic.css file via explicit import
                 const perFileCss = {per_file_css};
-                global.{css_file_store_ref}.get({css_filepath}).resolve(perFileCss, cssSourcemapData, {css_filepath});
+                {resolve_per_file_css}
                 
                 // Generate and resolve the .style-this.js module
                 {style_this_module_code}
@@ -923,52 +1072,49 @@ pub async fn evaluate_program<'alloc>(
         } else {
             // Non-atomic mode: existing behavior
             // Build source map metadata for JavaScript
-            let sourcemap_data = css_variable_identifiers
-                .iter()
-                .map(|css_var| {
-                    let line = css_var.span.start;
-                    let end_line = css_var.span.end;
-                    format!(
-                        "{{className:'{}',start:{},end:{}}}",
-                        css_var.class_name.replace('\'', "\\'"),
-                        line,
-                        end_line
-                    )
-                })
-                .collect::<Vec<_>>()
-                .join(",");
+            let sourcemap_data = css_sourcemap_data(&css_variable_identifiers);
 
-            let css = css_variable_identifiers
-                .into_iter()
+            let mut css = css_variable_identifiers
+                .iter()
                 .map(|css_var| {
-                    if css_var.class_name.starts_with("_Global") {
-                        return format!("`${{{}.css}}\n`", css_var.variable_name);
-                    }
-                    if transformer.wrap_selectors_with_global {
-                        return format!("`:global(.{}) {{\n${{{}.css}}\n}}`", css_var.class_name, css_var.variable_name);
-                    }
-
-                    format!("`.{} {{\n${{{}.css}}\n}}`", css_var.class_name, css_var.variable_name)
+                    let css = format!("${{{}.css}}", css_var.variable_name);
+                    format!("`{}`", css_var.css_block(&css, transformer.wrap_selectors_with_global))
                 })
-                .collect::<Vec<_>>()
-                .join(",\n");
+                .collect::<Vec<_>>();
+            if let Some(theme_css) = &theme_css {
+                css.push(format!("globalThis.{}", theme_css.js_ref));
+            }
+            let css = css.join(",\n");
 
-            eval_program_js.push_str(&formatdoc!(
-                "
-                const cssSourcemapData = [{sourcemap_data}];
-                global.{css_file_store_ref}.get({css_filepath}).resolve([\n{css}\n].join('\\n'), cssSourcemapData, {css_filepath});
-                ",
-            ));
+            if transformer.return_css || theme.is_some() {
+                eval_program_js.push_str(&formatdoc!(
+                    "
+                    return {{ css: [\n{css}\n].join('\\n'){class_lists_field} }};
+                    ",
+                ));
+            } else {
+                eval_program_js.push_str(&formatdoc!(
+                    "
+                    const cssSourcemapData = [{sourcemap_data}];
+                    global.{css_file_store_ref}.get({css_filepath}).resolve([\n{css}\n].join('\\n'), cssSourcemapData, {css_filepath});
+                    ",
+                ));
+            }
         }
     }
 
+    // returned by the CSS code above with `returnCss`
+    if !class_lists_field.is_empty() {
+        eval_program_js.push_str(&format!("\nreturn {{ {} }};", &class_lists_field[2..]));
+    }
+
     if transformer.debug {
         let importer_part = if let Some(importer_filepath) = importer_filepath {
             format!(" ({importer_filepath})")
         } else {
             String::new()
         };
-        let key = format!("{program_filepath}{importer_part}: {referenced_idents:?}");
+        let key = format!("{cache_key}{importer_part}: {referenced_idents:?}");
         js_sys::eval(&format!(
             "
             if(global.{PREFIX}_temporaryPrograms)
@@ -988,7 +1134,7 @@ pub async fn evaluate_program<'alloc>(
     // wrap into promise
     let eval_program_js = if let Some(require_ref) = &transformer.require_ref {
         let atomic_funcs = if transformer.atomic {
-            format!("__styleThis_cssToAtomicClassList: globalThis.__styleThis_cssToAtomicClassList,\n                __styleThis_getAtomicCss: globalThis.__styleThis_getAtomicCss,\n                __styleThis_extractNonAtomicCss: globalThis.__styleThis_extractNonAtomicCss,")
+            "__styleThis_cssToAtomicClassList: globalThis.__styleThis_cssToAtomicClassList,\n                __styleThis_cssToAtomicClassKeys: globalThis.__styleThis_cssToAtomicClassKeys,\n                __styleThis_getAtomicCss: globalThis.__styleThis_getAtomicCss,\n                __styleThis_extractNonAtomicCss: globalThis.__styleThis_extractNonAtomicCss,".to_string()
         } else {
             String::new()
         };
@@ -1014,7 +1160,7 @@ pub async fn evaluate_program<'alloc>(
         )
     } else {
         let atomic_funcs = if transformer.atomic {
-            format!("__styleThis_cssToAtomicClassList,\n                __styleThis_getAtomicCss,\n                __styleThis_extractNonAtomicCss,")
+            "__styleThis_cssToAtomicClassList,\n                __styleThis_cssToAtomicClassKeys,\n                __styleThis_getAtomicCss,\n                __styleThis_extractNonAtomicCss,".to_string()
         } else {
             String::new()
         };
@@ -1036,6 +1182,12 @@ pub async fn evaluate_program<'alloc>(
         )
     };
 
+    register_object_to_css();
+    register_compose();
+    if transformer.atomic {
+        register_atomic_cx();
+    }
+
     let evaluated =
         match js_sys::eval(&eval_program_js).map_err(|cause| TransformError::EvaluationFailed {
             filepath: program_filepath.clone(),
@@ -1048,20 +1200,14 @@ pub async fn evaluate_program<'alloc>(
         }) {
             Ok(v) => v,
             Err(err) => {
-                let err = ExportedJSValue::new(err.into());
-                js_sys::eval(&format!(
-                    "
-                    {css_file_store_ref}.get({css_filepath}).resolve({err});
-                    "
-                ))
-                .unwrap();
-                return;
+                report_error(tx, err);
+                return None;
             }
         };
 
     let promise = js_sys::Promise::from(evaluated);
     let future = wasm_bindgen_futures::JsFuture::from(promise);
-    if let Err(err) = future
+    let succeeded = match future
         .await
         .inspect_err(|err| {
             error_mapping::resolve_err(
@@ -1074,29 +1220,480 @@ pub async fn evaluate_program<'alloc>(
             );
         })
         .map_err(|cause| TransformError::EvaluationFailed {
-            filepath: program_filepath,
+            filepath: program_filepath.clone(),
             program: if transformer.debug {
                 Some(eval_program_js.to_string())
             } else {
                 None
             },
             cause,
-        })
-    {
-        let err = ExportedJSValue::new(err.into());
-        js_sys::eval(&format!(
-            "
-            {css_file_store_ref}.get({css_filepath}).resolve({err});
-            "
-        ))
-        .unwrap();
-    }
+        }) {
+        Ok(evaluated) => {
+            if !class_lists_field.is_empty() {
+                replace_class_lists(ast_builder, program, &evaluated);
+                let result = output_result(
+                    transformer,
+                    program,
+                    &program_filepath,
+                    program_code,
+                    &unused_css_blocks,
+                );
+                if transformer.return_css {
+                    transform_result = Some(result);
+                } else if let Some(tx) = tx.take() {
+                    let _ = tx.send(Ok(Some(result.into())));
+                }
+            }
+            if let Some(tx) = tx {
+                let result = if theme.is_some() {
+                    js_sys::Reflect::get(&evaluated, &JsValue::from_str("css"))
+                        .ok()
+                        .filter(|css| !css.is_undefined())
+                } else {
+                    transform_result.map(|result| {
+                        set_evaluated_css(
+                            &result,
+                            &evaluated,
+                            &css_variable_identifiers,
+                            &program_filepath,
+                            program_code,
+                        );
+                        result.into()
+                    })
+                };
+                let _ = tx.send(Ok(result));
+            }
+            true
+        }
+        Err(err) => {
+            report_error(tx, err);
+            false
+        }
+    };
     
+    // theme passes of the file's next transforms are skipped based on what it imported
+    if succeeded {
+        record_evaluated(&program_filepath);
+    }
+
     // Remove virtual CSS file from atomic sync tracking after CSS evaluation completes
     if let Some(virtual_css_path) = virtual_css_filepath {
         use crate::compiler::atomic_sync::GLOBAL_SYNC;
         GLOBAL_SYNC.remove(&virtual_css_path);
     }
+
+    (succeeded && !entrypoint).then(|| VirtualProgram {
+        idents: requested_idents,
+        imports: imports.take(),
+        exported_idents: evaluated_idents,
+        js: eval_program_js,
+    })
+}
+
+/// Whether evaluating the file can load a module the theme replaces, following the imports
+/// recorded by earlier evaluations. Files not evaluated since they last changed count as
+/// affected.
+pub(crate) async fn is_affected_by_theme(
+    transformer: &Transformer,
+    module_store: &ModuleStore,
+    filepath: &str,
+    theme: &Theme,
+) -> bool {
+    let mut visited = HashSet::new();
+    let mut pending = vec![filepath.to_string()];
+
+    while let Some(importer) = pending.pop() {
+        if !visited.insert(importer.clone()) {
+            continue;
+        }
+        let Some(module_ids) = module_imports(&importer) else {
+            return true;
+        };
+
+        for module_id in module_ids {
+            let Ok(module) = module_store.load(transformer, &module_id, &importer).await else {
+                return true;
+            };
+            if theme.replacement(&module_id, &module.filepath).is_some() {
+                return true;
+            }
+            // node_modules imports aren't evaluated, so nothing is recorded for them
+            if module.code.is_empty() {
+                continue;
+            }
+            // an edited dependency forgets its imports, see `lookup_module`
+            lookup_module(&module.filepath, &module.code);
+            pending.push(module.filepath.clone());
+        }
+    }
+
+    false
+}
+
+/// loads an imported module, or the module a theme replaces it with
+async fn load_import(
+    transformer: &Transformer,
+    module_store: &ModuleStore,
+    module_id: &str,
+    importer: &str,
+    theme: Option<&Theme>,
+) -> Result<Rc<LoadedModule>, TransformError> {
+    let module = module_store.load(transformer, module_id, importer).await?;
+    match theme.and_then(|theme| theme.replacement(module_id, &module.filepath)) {
+        Some(replacement) => module_store.load(transformer, replacement, importer).await,
+        None => Ok(module),
+    }
+}
+
+/// Evaluates the exports of an imported module the importer needs, unless they are cached.
+/// Modules are only parsed if no virtual program was built for the same exports before.
+#[allow(clippy::too_many_arguments)]
+async fn evaluate_dependency(
+    transformer: &Transformer,
+    project_root: &str,
+    module_store: Rc<ModuleStore>,
+    module_id: &str,
+    module: &LoadedModule,
+    importer: &str,
+    mut referenced_idents: HashSet<String>,
+    skip_css_eval: bool,
+    theme: Option<&Theme>,
+) -> Result<(), TransformError> {
+    let filepath = &module.filepath;
+    let cache_key = Theme::cache_key(theme, filepath);
+    record_import(importer, module_id, filepath);
+
+    // unchanged modules that don't export any referenced ident don't need a reparse
+    let cached_module = lookup_module(filepath, &module.code);
+    if cached_module.exports_any(&referenced_idents) == Some(false) {
+        count_lookup(true);
+        return Ok(());
+    }
+
+    // values evaluated earlier, e.g. by another file of the batch, don't need a reparse
+    let value_cache = VALUE_CACHE.with(|cache| cache.borrow().get(&cache_key).cloned());
+    if let Some(value_cache) = value_cache {
+        let value_cache = value_cache.lock().await;
+        referenced_idents.retain(|ident| !value_cache.contains(ident));
+    }
+
+    if referenced_idents.is_empty() {
+        return Ok(());
+    }
+
+    let mut idents = referenced_idents.iter().cloned().collect::<Vec<_>>();
+    idents.sort();
+    let key = VirtualProgram::key(&transformer.value_cache_ref, &cache_key, &idents);
+    if let Some(virtual_program) = cached_module.virtual_program(&key) {
+        match evaluate_virtual_program(
+            transformer,
+            project_root,
+            &module_store,
+            filepath,
+            &cache_key,
+            &virtual_program,
+            skip_css_eval,
+            theme,
+        )
+        .await
+        {
+            Ok(true) => {
+                count_lookup(true);
+                return Ok(());
+            }
+            Ok(false) => {}
+            // evaluated from the source below, which maps the error to it
+            Err(_) => cached_module.forget_virtual_program(&key),
+        }
+    }
+    count_lookup(false);
+
+    // JSON and raw text imports are evaluated as generated JS modules
+    let (code, source_type) =
+        match AssetKind::detect(module_id, filepath, &transformer.raw_extensions) {
+            Some(asset_kind) => (
+                asset_kind.to_module_code(filepath, &module.code)?,
+                SourceType::mjs(),
+            ),
+            None => {
+                let source_type = SourceType::from_path(filepath).map_err(|_| {
+                    TransformError::UknownExtension {
+                        filepath: filepath.clone(),
+                        row: 1,
+                        column: 1,
+                    }
+                })?;
+                (module.code.clone(), source_type)
+            }
+        };
+
+    let allocator = Allocator::default();
+    let ast_builder = AstBuilder::new(&allocator);
+
+    let ast = Parser::new(&allocator, &code, source_type)
+        .with_options(ParseOptions {
+            parse_regular_expression: true,
+            ..ParseOptions::default()
+        })
+        .parse();
+
+    if ast.panicked {
+        return Err(TransformError::RawParseFailed {
+            filepath: filepath.clone(),
+            message: ast.errors.first().unwrap().message.to_string(),
+            row: 1,
+            column: 1,
+        });
+    }
+
+    let mut program = ast.program;
+    cached_module.scan_exports(&program);
+    let (tx, rx) = futures::channel::oneshot::channel();
+
+    let virtual_program = evaluate_program(
+        &ast_builder,
+        transformer,
+        false,
+        project_root,
+        filepath.clone(),
+        Some(importer),
+        &code,
+        &mut program,
+        referenced_idents,
+        module_store,
+        None,
+        Some(tx),
+        skip_css_eval,
+        false,
+        theme,
+        "",
+    )
+    .await;
+
+    // a dropped sender means the dependency had nothing to report
+    rx.await.unwrap_or(Ok(None))?;
+
+    if let Some(virtual_program) = virtual_program {
+        cached_module.store_virtual_program(&transformer.value_cache_ref, &cache_key, virtual_program);
+    }
+
+    Ok(())
+}
+
+/// Evaluates a virtual program built before, after the dependencies it reads. Returns `false`
+/// without evaluating it when the values it evaluates changed since the caller looked it up.
+#[allow(clippy::too_many_arguments)]
+fn evaluate_virtual_program<'a>(
+    transformer: &'a Transformer,
+    project_root: &'a str,
+    module_store: &'a Rc<ModuleStore>,
+    filepath: &'a str,
+    cache_key: &'a str,
+    virtual_program: &'a VirtualProgram,
+    skip_css_eval: bool,
+    theme: Option<&'a Theme>,
+) -> LocalBoxFuture<'a, Result<bool, TransformError>> {
+    Box::pin(async move {
+        let value_cache_guard = VALUE_CACHE.with(|cache| {
+            cache
+                .borrow_mut()
+                .entry(cache_key.to_string())
+                .or_insert_with(|| Rc::new(FutureMutex::new(HashSet::new())))
+                .clone()
+        });
+        let mut value_cache = value_cache_guard.lock().await;
+
+        let pending = virtual_program
+            .idents
+            .iter()
+            .filter(|ident| !value_cache.contains(*ident))
+            .count();
+        if pending == 0 {
+            return Ok(true);
+        }
+        if pending != virtual_program.idents.len() {
+            return Ok(false);
+        }
+
+        let imports = virtual_program.imports.iter().map(|(module_id, idents)| async move {
+            let module = load_import(transformer, module_store, module_id, filepath, theme).await?;
+            evaluate_dependency(
+                transformer,
+                project_root,
+                module_store.clone(),
+                module_id,
+                &module,
+                filepath,
+                idents.clone(),
+                skip_css_eval,
+                theme,
+            )
+            .await
+        });
+        futures::future::try_join_all(imports).await?;
+
+        register_object_to_css();
+        register_compose();
+        if transformer.atomic {
+            register_atomic_cx();
+        }
+
+        let evaluation_failed = |cause| TransformError::EvaluationFailed {
+            filepath: filepath.to_string(),
+            program: transformer.debug.then(|| virtual_program.js.clone()),
+            cause,
+        };
+        let evaluated = js_sys::eval(&virtual_program.js).map_err(evaluation_failed)?;
+        wasm_bindgen_futures::JsFuture::from(js_sys::Promise::from(evaluated))
+            .await
+            .map_err(evaluation_failed)?;
+
+        value_cache.extend(virtual_program.exported_idents.iter().cloned());
+        Ok(true)
+    })
+}
+
+/// builds the `cssSourcemapData` array entries passed along with the CSS
+fn css_sourcemap_data(css_variable_identifiers: &[CssVariableIdentifier]) -> String {
+    css_variable_identifiers
+        .iter()
+        .map(|css_var| {
+            let selector = match css_var.kind {
+                CssBlockKind::Keyframes | CssBlockKind::Global => format!(
+                    ",selector:'{}'",
+                    css_var.selector().unwrap_or_default().replace('\'', "\\'")
+                ),
+                CssBlockKind::Class => String::new(),
+            };
+            format!(
+                "{{className:'{}',start:{},end:{}{selector}}}",
+                css_var.class_name.replace('\'', "\\'"),
+                css_var.span.start,
+                css_var.span.end
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// joins the CSS of all templates if each of them was folded at compile time
+fn static_css(
+    transformer: &Transformer,
+    css_variable_identifiers: &[CssVariableIdentifier],
+) -> Option<String> {
+    css_variable_identifiers
+        .iter()
+        .map(|css_var| {
+            let css = css_var.static_css.as_ref()?;
+            Some(css_var.css_block(css, transformer.wrap_selectors_with_global))
+        })
+        .collect::<Option<Vec<_>>>()
+        .map(|css| css.join("\n"))
+}
+
+/// generates the code of an entrypoint, its CSS is added by `set_evaluated_css` with
+/// `returnCss`
+fn output_result(
+    transformer: &Transformer,
+    program: &Program,
+    program_filepath: &str,
+    program_code: &str,
+    unused_css_blocks: &[UnusedCssBlock],
+) -> js_sys::Object {
+    let options = CodegenOptions {
+        source_map_path: Some(PathBuf::from_str(program_filepath).unwrap()),
+        ..Default::default()
+    };
+    let output_js = Codegen::new().with_options(options).build(program);
+
+    let result = js_sys::Object::new();
+    js_sys::Reflect::set(
+        &result,
+        &JsValue::from_str("code"),
+        &JsValue::from_str(&format!("// @ts-nocheck\n{}", output_js.code)),
+    )
+    .unwrap();
+
+    if !unused_css_blocks.is_empty() {
+        js_sys::Reflect::set(
+            &result,
+            &JsValue::from_str("warnings"),
+            &unused_css_warnings(unused_css_blocks, program_filepath, program_code),
+        )
+        .unwrap();
+    }
+
+    let map_key = if transformer.return_css { "map" } else { "sourcemap" };
+    js_sys::Reflect::set(
+        &result,
+        &JsValue::from_str(map_key),
+        &JsValue::from_str(&output_js.map.unwrap().to_json_string()),
+    )
+    .unwrap();
+
+    result
+}
+
+/// replaces the placeholders of class lists with extra classes only known once evaluated
+/// (see `VisitorTransformer::output_class_list`) with the evaluated `classLists`
+fn replace_class_lists<'alloc>(
+    ast_builder: &AstBuilder<'alloc>,
+    program: &mut Program<'alloc>,
+    evaluated: &JsValue,
+) {
+    struct ClassListReplacer<'a, 'alloc> {
+        ast_builder: &'a AstBuilder<'alloc>,
+        class_lists: JsValue,
+    }
+
+    impl<'alloc> VisitMut<'alloc> for ClassListReplacer<'_, 'alloc> {
+        fn visit_string_literal(&mut self, it: &mut oxc_ast::ast::StringLiteral<'alloc>) {
+            if let Some(class_list) =
+                js_sys::Reflect::get(&self.class_lists, &JsValue::from_str(&it.value))
+                    .ok()
+                    .and_then(|class_list| class_list.as_string())
+            {
+                it.value = self.ast_builder.atom(&class_list);
+                it.raw = None;
+            }
+        }
+    }
+
+    let Ok(class_lists) = js_sys::Reflect::get(evaluated, &JsValue::from_str("classLists")) else {
+        return;
+    };
+    ClassListReplacer {
+        ast_builder,
+        class_lists,
+    }
+    .visit_program(program);
+}
+
+/// fills in the CSS fields of an entrypoint result from the evaluated program's return value
+fn set_evaluated_css(
+    result: &js_sys::Object,
+    evaluated: &JsValue,
+    css_variable_identifiers: &[CssVariableIdentifier],
+    program_filepath: &str,
+    program_code: &str,
+) {
+    let get = |key: &str| {
+        js_sys::Reflect::get(evaluated, &JsValue::from_str(key))
+            .ok()
+            .and_then(|value| value.as_string())
+    };
+
+    let css = get("css").unwrap_or_default();
+    let css_map = generate_css_sourcemap(&css, css_variable_identifiers, program_filepath, program_code)
+        .map(|map| JsValue::from_str(&map.to_json_string()))
+        .unwrap_or(JsValue::UNDEFINED);
+    let atomic_module = get("atomicModule")
+        .map(|atomic_module| JsValue::from_str(&atomic_module))
+        .unwrap_or(JsValue::UNDEFINED);
+
+    js_sys::Reflect::set(result, &JsValue::from_str("css"), &JsValue::from_str(&css)).unwrap();
+    js_sys::Reflect::set(result, &JsValue::from_str("cssMap"), &css_map).unwrap();
+    js_sys::Reflect::set(result, &JsValue::from_str("atomicModule"), &atomic_module).unwrap();
 }
 
 // EvaluateProgramReturnStatus is now in compiler/types.rs
@@ -0,0 +1,160 @@
+use crate::{LIBRARY_CORE_IMPORT_NAME, LIBRARY_REACT_IMPORT_NAME, LIBRARY_SOLID_JS_IMPORT_NAME};
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+/// What an export of a library module is used for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum LibraryRole {
+    Css,
+    Style,
+    ExtraClass,
+    Keyframes,
+    GlobalCss,
+    CreateTokens,
+    Compose,
+    Cx,
+    Styled(StyledFramework),
+}
+
+/// Which styled component prepass handles a `styled` export
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum StyledFramework {
+    React,
+    Solid,
+}
+
+/// Export names of a module providing the style-this APIs, either one of our packages or a
+/// module re-exporting them (e.g. a design system)
+#[derive(Debug, Clone)]
+pub(crate) struct LibraryImport {
+    /// (export name, role)
+    exports: Vec<(String, LibraryRole)>,
+}
+
+impl LibraryImport {
+    fn new(exports: &[(&str, LibraryRole)]) -> Self {
+        Self {
+            exports: exports
+                .iter()
+                .map(|(name, role)| (name.to_string(), *role))
+                .collect(),
+        }
+    }
+
+    /// role of a named import, e.g. `css` in `import { css as c } from "..."`
+    pub fn role(&self, export_name: &str) -> Option<LibraryRole> {
+        self.exports
+            .iter()
+            .find(|(name, _)| name == export_name)
+            .map(|(_, role)| *role)
+    }
+
+    /// all exports, used for namespace and default imports
+    pub fn exports(&self) -> impl Iterator<Item = (&str, LibraryRole)> {
+        self.exports
+            .iter()
+            .map(|(name, role)| (name.as_str(), *role))
+    }
+
+    /// reads one entry of the `libraryImports` option: export names for
+    /// `css`, `style`, `extraClass`, `keyframes`, `globalCss`, `createTokens`, `compose`, `cx`
+    /// and `styled`, plus the `framework` (`"react"` or `"solid"`) of `styled`
+    fn from_js(value: &JsValue) -> Self {
+        let get = |key: &str| {
+            js_sys::Reflect::get(value, &JsValue::from_str(key))
+                .ok()
+                .and_then(|value| value.as_string())
+        };
+
+        let framework = match get("framework").as_deref() {
+            Some("solid") => StyledFramework::Solid,
+            _ => StyledFramework::React,
+        };
+
+        let exports = [
+            ("css", LibraryRole::Css),
+            ("style", LibraryRole::Style),
+            ("extraClass", LibraryRole::ExtraClass),
+            ("keyframes", LibraryRole::Keyframes),
+            ("globalCss", LibraryRole::GlobalCss),
+            ("createTokens", LibraryRole::CreateTokens),
+            ("compose", LibraryRole::Compose),
+            ("cx", LibraryRole::Cx),
+            ("styled", LibraryRole::Styled(framework)),
+        ]
+        .into_iter()
+        .filter_map(|(key, role)| Some((get(key)?, role)))
+        .collect();
+
+        Self { exports }
+    }
+}
+
+/// our own packages, extended by the `libraryImports` option
+pub(crate) fn parse_library_imports(opts: &JsValue) -> HashMap<String, LibraryImport> {
+    let mut library_imports = default_library_imports();
+
+    let Some(option) = js_sys::Reflect::get(opts, &JsValue::from_str("libraryImports"))
+        .ok()
+        .and_then(|v| v.dyn_into::<js_sys::Object>().ok())
+    else {
+        return library_imports;
+    };
+
+    for key in js_sys::Object::keys(&option).iter() {
+        let (Some(source), Ok(value)) = (key.as_string(), js_sys::Reflect::get(&option, &key))
+        else {
+            continue;
+        };
+        library_imports.insert(source, LibraryImport::from_js(&value));
+    }
+
+    library_imports
+}
+
+fn default_library_imports() -> HashMap<String, LibraryImport> {
+    HashMap::from([
+        (
+            LIBRARY_CORE_IMPORT_NAME.to_string(),
+            LibraryImport::new(&[
+                ("css", LibraryRole::Css),
+                ("style", LibraryRole::Style),
+                ("extraClass", LibraryRole::ExtraClass),
+                ("keyframes", LibraryRole::Keyframes),
+                ("globalCss", LibraryRole::GlobalCss),
+                ("createTokens", LibraryRole::CreateTokens),
+                ("compose", LibraryRole::Compose),
+                ("cx", LibraryRole::Cx),
+            ]),
+        ),
+        (
+            LIBRARY_REACT_IMPORT_NAME.to_string(),
+            LibraryImport::new(&[("styled", LibraryRole::Styled(StyledFramework::React))]),
+        ),
+        (
+            LIBRARY_SOLID_JS_IMPORT_NAME.to_string(),
+            LibraryImport::new(&[("styled", LibraryRole::Styled(StyledFramework::Solid))]),
+        ),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_library_imports() {
+        let library_imports = default_library_imports();
+
+        let core = &library_imports[LIBRARY_CORE_IMPORT_NAME];
+        assert_eq!(core.role("css"), Some(LibraryRole::Css));
+        assert_eq!(core.role("extraClass"), Some(LibraryRole::ExtraClass));
+        assert_eq!(core.role("styled"), None);
+
+        let solid = &library_imports[LIBRARY_SOLID_JS_IMPORT_NAME];
+        assert_eq!(
+            solid.exports().collect::<Vec<_>>(),
+            [("styled", LibraryRole::Styled(StyledFramework::Solid))]
+        );
+    }
+}
@@ -1,10 +1,25 @@
+mod assets;
 mod cache;
+mod class_name;
+mod compose;
+#[allow(clippy::module_inception)]
 mod compiler;
 mod css_sourcemap;
+mod cx;
+mod dynamic_style;
 mod error;
 mod evaluator;
+mod library_imports;
+mod module_cache;
+mod module_store;
+mod object_css;
+mod scope_analysis;
+mod static_eval;
+mod style_object;
+mod themes;
 mod transformer;
 mod types;
+mod unused_css;
 mod visitor;
 pub mod atomic;
 pub mod atomic_sync;
@@ -0,0 +1,380 @@
+use super::cache::VALUE_CACHE;
+use oxc_ast::ast::{Declaration, Program, Statement};
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+
+thread_local! {
+    /// filepath -> module seen by a previous evaluation, kept across `transform` calls
+    static MODULE_CACHE: RefCell<HashMap<String, Rc<CachedModule>>> = RefCell::new(HashMap::new());
+    /// filepath -> files importing it, whose values are dropped along with its own
+    static MODULE_IMPORTERS: RefCell<HashMap<String, HashSet<String>>> = RefCell::new(HashMap::new());
+    /// filepath -> ids of the modules it read values from, known once the file was evaluated
+    static MODULE_IMPORTS: RefCell<HashMap<String, HashSet<String>>> = RefCell::new(HashMap::new());
+    static MODULE_CACHE_HITS: Cell<u32> = const { Cell::new(0) };
+    static MODULE_CACHE_MISSES: Cell<u32> = const { Cell::new(0) };
+}
+
+/// A dependency module identified by its path and content hash
+pub(crate) struct CachedModule {
+    hash: u64,
+    /// `None` until the module was parsed once
+    exports: RefCell<Option<ModuleExports>>,
+    /// see `VirtualProgram::key`
+    virtual_programs: RefCell<HashMap<String, Rc<VirtualProgram>>>,
+}
+
+/// Names a module exports, scanned from its top-level statements
+#[derive(Debug, Default, PartialEq)]
+struct ModuleExports {
+    names: HashSet<String>,
+    /// `export * from "..."` can export any name
+    export_all: bool,
+}
+
+/// The program evaluated for some exports of a dependency, it is evaluated again without
+/// parsing the module when the values are dropped, e.g. because a module it imports changed
+pub(crate) struct VirtualProgram {
+    /// sorted exports the program evaluates
+    pub idents: Vec<String>,
+    /// imports evaluated before the program, (module id, exports it needs)
+    pub imports: Vec<(String, HashSet<String>)>,
+    /// exports stored in the value cache once evaluated
+    pub exported_idents: HashSet<String>,
+    /// wrapped program, ready to be evaluated
+    pub js: String,
+}
+
+impl VirtualProgram {
+    /// programs differ per transformer, theme (through the value cache key) and exports
+    pub fn key(value_cache_ref: &str, cache_key: &str, idents: &[String]) -> String {
+        format!("{value_cache_ref}:{cache_key}:{}", idents.join(","))
+    }
+}
+
+impl CachedModule {
+    /// whether the module could export any of the idents, `None` if it wasn't scanned yet
+    pub fn exports_any(&self, idents: &HashSet<String>) -> Option<bool> {
+        self.exports.borrow().as_ref().map(|exports| {
+            exports.export_all || idents.iter().any(|ident| exports.names.contains(ident))
+        })
+    }
+
+    /// stores the exports of the parsed module for later lookups
+    pub fn scan_exports(&self, program: &Program) {
+        if self.exports.borrow().is_some() {
+            return;
+        }
+        *self.exports.borrow_mut() = Some(scan_exports(program));
+    }
+
+    pub fn virtual_program(&self, key: &str) -> Option<Rc<VirtualProgram>> {
+        self.virtual_programs.borrow().get(key).cloned()
+    }
+
+    pub fn store_virtual_program(&self, value_cache_ref: &str, cache_key: &str, program: VirtualProgram) {
+        let key = VirtualProgram::key(value_cache_ref, cache_key, &program.idents);
+        self.virtual_programs.borrow_mut().insert(key, Rc::new(program));
+    }
+
+    pub fn forget_virtual_program(&self, key: &str) {
+        self.virtual_programs.borrow_mut().remove(key);
+    }
+}
+
+/// Returns the cache entry of a module. When the content changed since the last lookup,
+/// values evaluated from the previous content, and from files importing it, are dropped so
+/// they are evaluated again.
+pub(crate) fn lookup_module(filepath: &str, code: &str) -> Rc<CachedModule> {
+    let mut hasher = DefaultHasher::new();
+    code.hash(&mut hasher);
+    let hash = hasher.finish();
+
+    MODULE_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if let Some(module) = cache.get(filepath)
+            && module.hash == hash
+        {
+            return module.clone();
+        }
+
+        if cache.contains_key(filepath) {
+            drop_values(filepath);
+        }
+        // the new content can import other modules
+        MODULE_IMPORTS.with(|imports| imports.borrow_mut().remove(filepath));
+
+        let module = Rc::new(CachedModule {
+            hash,
+            exports: RefCell::new(None),
+            virtual_programs: Default::default(),
+        });
+        cache.insert(filepath.to_string(), module.clone());
+        module
+    })
+}
+
+/// remembers that `importer` reads values of `filepath`, which it imports as `module_id`
+pub(crate) fn record_import(importer: &str, module_id: &str, filepath: &str) {
+    MODULE_IMPORTERS.with(|importers| {
+        importers
+            .borrow_mut()
+            .entry(filepath.to_string())
+            .or_default()
+            .insert(importer.to_string());
+    });
+    MODULE_IMPORTS.with(|imports| {
+        imports
+            .borrow_mut()
+            .entry(importer.to_string())
+            .or_default()
+            .insert(module_id.to_string());
+    });
+}
+
+/// remembers that the imports of the file are recorded, including when it has none
+pub(crate) fn record_evaluated(filepath: &str) {
+    MODULE_IMPORTS.with(|imports| {
+        imports.borrow_mut().entry(filepath.to_string()).or_default();
+    });
+}
+
+/// ids of the modules the file read values from, `None` if it wasn't evaluated since it last
+/// changed
+pub(crate) fn module_imports(filepath: &str) -> Option<Vec<String>> {
+    MODULE_IMPORTS.with(|imports| {
+        imports
+            .borrow()
+            .get(filepath)
+            .map(|module_ids| module_ids.iter().cloned().collect())
+    })
+}
+
+/// counts a dependency which was evaluated without parsing it (hit) or had to be parsed (miss)
+pub(crate) fn count_lookup(hit: bool) {
+    let counter = if hit {
+        &MODULE_CACHE_HITS
+    } else {
+        &MODULE_CACHE_MISSES
+    };
+    counter.with(|count| count.set(count.get() + 1));
+}
+
+/// drops the evaluated values of the file and of all files importing it, directly or not
+fn drop_values(filepath: &str) {
+    let mut dropped = HashSet::new();
+    let mut pending = vec![filepath.to_string()];
+    while let Some(filepath) = pending.pop() {
+        if !dropped.insert(filepath.clone()) {
+            continue;
+        }
+        MODULE_IMPORTERS.with(|importers| {
+            if let Some(importers) = importers.borrow().get(&filepath) {
+                pending.extend(importers.iter().cloned());
+            }
+        });
+    }
+
+    VALUE_CACHE.with(|cache| {
+        cache.borrow_mut().retain(|key, _| {
+            // including the values evaluated for each theme, see `Theme::cache_key`
+            let filepath = key.split_once("?theme=").map_or(key.as_str(), |(filepath, _)| filepath);
+            !dropped.contains(filepath)
+        })
+    });
+}
+
+fn scan_exports(program: &Program) -> ModuleExports {
+    let mut exports = ModuleExports::default();
+
+    for stmt in &program.body {
+        match stmt {
+            Statement::ExportNamedDeclaration(export) => {
+                for specifier in &export.specifiers {
+                    exports.names.insert(specifier.exported.name().to_string());
+                }
+                match &export.declaration {
+                    Some(Declaration::VariableDeclaration(declaration)) => {
+                        for declarator in &declaration.declarations {
+                            for ident in declarator.id.get_binding_identifiers() {
+                                exports.names.insert(ident.name.to_string());
+                            }
+                        }
+                    }
+                    Some(declaration) => {
+                        if let Some(ident) = declaration.id() {
+                            exports.names.insert(ident.name.to_string());
+                        }
+                    }
+                    None => {}
+                }
+            }
+            Statement::ExportDefaultDeclaration(_) => {
+                exports.names.insert("default".to_string());
+            }
+            Statement::ExportAllDeclaration(export) => match &export.exported {
+                Some(exported) => {
+                    exports.names.insert(exported.name().to_string());
+                }
+                None => exports.export_all = true,
+            },
+            _ => {}
+        }
+    }
+
+    exports
+}
+
+/// Module cache counters for debugging (JavaScript API)
+#[wasm_bindgen]
+pub fn module_cache_stats() -> JsValue {
+    let stats = js_sys::Object::new();
+    let set = |key: &str, value: u32| {
+        js_sys::Reflect::set(&stats, &JsValue::from_str(key), &JsValue::from(value)).unwrap();
+    };
+    set("hits", MODULE_CACHE_HITS.with(Cell::get));
+    set("misses", MODULE_CACHE_MISSES.with(Cell::get));
+    set(
+        "modules",
+        MODULE_CACHE.with(|cache| cache.borrow().len() as u32),
+    );
+    stats.into()
+}
+
+/// Clear the module cache and its counters (JavaScript API)
+#[wasm_bindgen]
+pub fn clear_module_cache() {
+    MODULE_CACHE.with(|cache| cache.borrow_mut().clear());
+    MODULE_IMPORTERS.with(|importers| importers.borrow_mut().clear());
+    MODULE_IMPORTS.with(|imports| imports.borrow_mut().clear());
+    MODULE_CACHE_HITS.with(|hits| hits.set(0));
+    MODULE_CACHE_MISSES.with(|misses| misses.set(0));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    #[test]
+    fn test_scan_exports() {
+        let allocator = Allocator::default();
+        let code = r#"
+            import { a } from "./a";
+            export const { color, size: fontSize } = theme;
+            export function spacing() {}
+            export { a, a as b };
+            export * as icons from "./icons";
+            export default 1;
+        "#;
+        let program = Parser::new(&allocator, code, SourceType::mjs())
+            .parse()
+            .program;
+
+        let exports = scan_exports(&program);
+
+        let mut names = exports.names.iter().map(String::as_str).collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(
+            names,
+            ["a", "b", "color", "default", "fontSize", "icons", "spacing"]
+        );
+        assert!(!exports.export_all);
+    }
+
+    #[test]
+    fn test_lookup_module() {
+        clear_module_cache();
+        VALUE_CACHE.with(|cache| {
+            cache
+                .borrow_mut()
+                .insert("/p/theme.ts".to_string(), Default::default())
+        });
+
+        let module = lookup_module("/p/theme.ts", "export const a = 1;");
+        assert_eq!(module.exports_any(&HashSet::from(["a".to_string()])), None);
+
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, "export * from './b';", SourceType::mjs())
+            .parse()
+            .program;
+        module.scan_exports(&program);
+        module.store_virtual_program(
+            "vars",
+            "/p/theme.ts",
+            VirtualProgram {
+                idents: vec!["a".to_string()],
+                imports: vec![],
+                exported_idents: HashSet::from(["a".to_string()]),
+                js: String::new(),
+            },
+        );
+
+        let module = lookup_module("/p/theme.ts", "export const a = 1;");
+        assert_eq!(
+            module.exports_any(&HashSet::from(["x".to_string()])),
+            Some(true)
+        );
+        let key = VirtualProgram::key("vars", "/p/theme.ts", &["a".to_string()]);
+        assert!(module.virtual_program(&key).is_some());
+        assert!(VALUE_CACHE.with(|cache| cache.borrow().contains_key("/p/theme.ts")));
+
+        // changed content drops evaluated values and the programs built from it
+        let module = lookup_module("/p/theme.ts", "export const a = 2;");
+        assert_eq!(module.exports_any(&HashSet::new()), None);
+        assert!(module.virtual_program(&key).is_none());
+        assert!(!VALUE_CACHE.with(|cache| cache.borrow().contains_key("/p/theme.ts")));
+    }
+
+    #[test]
+    fn test_changes_drop_values_of_importers() {
+        clear_module_cache();
+        for key in [
+            "/p/tokens.ts",
+            "/p/theme.ts",
+            "/p/theme.ts?theme=dark",
+            "/p/entry.tsx",
+            "/p/unrelated.ts",
+        ] {
+            VALUE_CACHE.with(|cache| cache.borrow_mut().insert(key.to_string(), Default::default()));
+        }
+        record_import("/p/theme.ts", "./tokens", "/p/tokens.ts");
+        record_import("/p/entry.tsx", "./theme", "/p/theme.ts");
+        // cycles don't keep the invalidation going
+        record_import("/p/tokens.ts", "./entry", "/p/entry.tsx");
+
+        lookup_module("/p/tokens.ts", "export const size = 1;");
+        lookup_module("/p/tokens.ts", "export const size = 2;");
+
+        let mut keys = VALUE_CACHE.with(|cache| cache.borrow().keys().cloned().collect::<Vec<_>>());
+        keys.sort();
+        assert_eq!(keys, ["/p/unrelated.ts"]);
+
+        // the imports of the changed module are recorded again when it's evaluated
+        assert_eq!(module_imports("/p/tokens.ts"), None);
+        assert_eq!(module_imports("/p/theme.ts"), Some(vec!["./tokens".to_string()]));
+        record_evaluated("/p/tokens.ts");
+        assert_eq!(module_imports("/p/tokens.ts"), Some(vec![]));
+    }
+
+    #[test]
+    fn test_count_lookup() {
+        clear_module_cache();
+
+        lookup_module("/p/theme.ts", "export const a = 1;");
+        lookup_module("/p/theme.ts", "export const a = 1;");
+        assert_eq!(MODULE_CACHE_HITS.with(Cell::get), 0);
+        assert_eq!(MODULE_CACHE_MISSES.with(Cell::get), 0);
+
+        // only evaluations that skipped parsing the module are hits
+        count_lookup(false);
+        count_lookup(true);
+        count_lookup(true);
+        assert_eq!(MODULE_CACHE_HITS.with(Cell::get), 2);
+        assert_eq!(MODULE_CACHE_MISSES.with(Cell::get), 1);
+    }
+}
@@ -0,0 +1,111 @@
+use super::error::TransformError;
+use super::transformer::Transformer;
+use futures::FutureExt;
+use futures::future::{LocalBoxFuture, Shared};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::Path;
+use std::rc::Rc;
+
+/// A dependency as returned by the `loadFile` option
+pub(crate) struct LoadedModule {
+    pub filepath: String,
+    pub code: String,
+}
+
+type ModuleLoad = Shared<LocalBoxFuture<'static, Result<Rc<LoadedModule>, TransformError>>>;
+
+/// Modules shared between all files transformed together, so a module imported by many files
+/// is only loaded once per batch. Loads are shared while still in flight, files transformed
+/// concurrently wait for the first load of a module instead of starting their own. What is
+/// parsed and evaluated from a module is shared through the module cache, see `VirtualProgram`.
+#[derive(Default)]
+pub(crate) struct ModuleStore {
+    /// (module id, importer directory) -> load of the module
+    modules: RefCell<HashMap<(String, String), ModuleLoad>>,
+}
+
+impl ModuleStore {
+    /// loads a module through the transformer, or returns the load started earlier in this batch
+    pub async fn load(
+        &self,
+        transformer: &Transformer,
+        id: &str,
+        importer: &str,
+    ) -> Result<Rc<LoadedModule>, TransformError> {
+        self.load_with(id, importer, || {
+            let transformer = transformer.clone();
+            let id = id.to_string();
+            let importer = importer.to_string();
+            async move {
+                let (filepath, code) = transformer.load_file(&id, &importer).await?;
+                Ok(Rc::new(LoadedModule { filepath, code }))
+            }
+            .boxed_local()
+        })
+        .await
+    }
+
+    /// awaits the load of the module, `load` starts it if this batch didn't yet
+    async fn load_with(
+        &self,
+        id: &str,
+        importer: &str,
+        load: impl FnOnce() -> LocalBoxFuture<'static, Result<Rc<LoadedModule>, TransformError>>,
+    ) -> Result<Rc<LoadedModule>, TransformError> {
+        // relative imports resolve the same way for all files of a directory
+        let importer_dir = Path::new(importer)
+            .parent()
+            .map(|dir| dir.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let key = (id.to_string(), importer_dir);
+
+        let load = self
+            .modules
+            .borrow_mut()
+            .entry(key)
+            .or_insert_with(|| load().shared())
+            .clone();
+
+        load.await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::future::join_all;
+    use std::cell::Cell;
+
+    #[tokio::test]
+    async fn test_concurrent_loads_are_shared() {
+        let store = ModuleStore::default();
+        let loads = Rc::new(Cell::new(0));
+
+        let load = |id: &'static str, importer: &'static str| {
+            let loads = loads.clone();
+            store.load_with(id, importer, move || {
+                async move {
+                    loads.set(loads.get() + 1);
+                    // still loading when the other files ask for the module
+                    tokio::task::yield_now().await;
+                    Ok(Rc::new(LoadedModule {
+                        filepath: format!("/p/{id}.ts"),
+                        code: String::new(),
+                    }))
+                }
+                .boxed_local()
+            })
+        };
+
+        let results = join_all([
+            load("./tokens", "/p/a.tsx"),
+            load("./tokens", "/p/b.tsx"),
+            load("./tokens", "/p/nested/c.tsx"),
+        ])
+        .await;
+
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(loads.get(), 2);
+    }
+}
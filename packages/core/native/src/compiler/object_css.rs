@@ -0,0 +1,261 @@
+use super::static_eval::{StaticValue, js_number_to_string, set_property};
+use crate::PREFIX;
+use wasm_bindgen::prelude::*;
+
+/// properties taking plain numbers, every other number gets a `px` unit
+const UNITLESS_PROPERTIES: &[&str] = &[
+    "animationIterationCount",
+    "aspectRatio",
+    "borderImageOutset",
+    "borderImageSlice",
+    "borderImageWidth",
+    "boxFlex",
+    "boxFlexGroup",
+    "boxOrdinalGroup",
+    "columnCount",
+    "columns",
+    "fillOpacity",
+    "flex",
+    "flexGrow",
+    "flexNegative",
+    "flexOrder",
+    "flexPositive",
+    "flexShrink",
+    "floodOpacity",
+    "fontWeight",
+    "gridArea",
+    "gridColumn",
+    "gridColumnEnd",
+    "gridColumnSpan",
+    "gridColumnStart",
+    "gridRow",
+    "gridRowEnd",
+    "gridRowSpan",
+    "gridRowStart",
+    "lineClamp",
+    "lineHeight",
+    "opacity",
+    "order",
+    "orphans",
+    "scale",
+    "stopOpacity",
+    "strokeDasharray",
+    "strokeDashoffset",
+    "strokeMiterlimit",
+    "strokeOpacity",
+    "strokeWidth",
+    "tabSize",
+    "widows",
+    "zIndex",
+    "zoom",
+];
+
+/// name of the global function converting style objects at evaluation time
+pub(crate) fn object_to_css_function_name() -> String {
+    format!("{PREFIX}_objectToCss")
+}
+
+/// converts a style object like `{ fontSize: 12, "&:hover": { color: "blue" } }` into the
+/// CSS a `css` template with the same content would contain
+pub(crate) fn object_to_css(properties: &[(String, StaticValue)]) -> Option<String> {
+    let mut lines = vec![];
+
+    for (key, value) in properties {
+        match value {
+            // nested selectors and at-rules
+            StaticValue::Object(nested) => {
+                lines.push(format!("{key} {{\n{}\n}}", object_to_css(nested)?));
+            }
+            // fallback values, e.g. `display: ["-webkit-box", "flex"]`
+            StaticValue::Array(values) => {
+                for value in values {
+                    lines.push(declaration(key, value)?);
+                }
+            }
+            value => lines.push(declaration(key, value)?),
+        }
+    }
+
+    Some(lines.join("\n"))
+}
+
+fn declaration(key: &str, value: &StaticValue) -> Option<String> {
+    let value = match value {
+        StaticValue::Number(number)
+            if *number != 0.0 && !key.starts_with("--") && !is_unitless(key) =>
+        {
+            format!("{}px", js_number_to_string(*number)?)
+        }
+        StaticValue::String(_) | StaticValue::Number(_) => value.to_js_string()?,
+        StaticValue::Array(_) | StaticValue::Object(_) => return None,
+    };
+
+    Some(format!("{}: {value};", property_name(key)))
+}
+
+/// checks the list in camelCase without vendor prefix, so `z-index`, `zIndex` and
+/// `WebkitLineClamp` are found alike
+fn is_unitless(key: &str) -> bool {
+    let mut name = String::with_capacity(key.len());
+    for (idx, segment) in key.trim_start_matches('-').split('-').enumerate() {
+        let mut chars = segment.chars();
+        match chars.next() {
+            Some(first) if idx > 0 => {
+                name.push(first.to_ascii_uppercase());
+                name.extend(chars);
+            }
+            _ => name.push_str(segment),
+        }
+    }
+
+    for prefix in ["Webkit", "webkit", "Moz", "moz", "ms", "O", "o"] {
+        if let Some(rest) = name.strip_prefix(prefix)
+            && rest.starts_with(|c: char| c.is_ascii_uppercase())
+        {
+            name = format!("{}{}", rest[..1].to_ascii_lowercase(), &rest[1..]);
+            break;
+        }
+    }
+
+    UNITLESS_PROPERTIES.contains(&name.as_str())
+}
+
+/// `backgroundColor` -> `background-color`, `WebkitBoxFlex` -> `-webkit-box-flex`
+fn property_name(key: &str) -> String {
+    if key.starts_with("--") || key.contains('-') {
+        return key.to_string();
+    }
+
+    let mut ret = String::with_capacity(key.len() + 4);
+    if key.starts_with("ms") && key[2..].starts_with(|c: char| c.is_ascii_uppercase()) {
+        ret.push('-');
+    }
+    for ch in key.chars() {
+        if ch.is_ascii_uppercase() {
+            ret.push('-');
+            ret.push(ch.to_ascii_lowercase());
+        } else {
+            ret.push(ch);
+        }
+    }
+    ret
+}
+
+/// reads an evaluated style object, skipping `null`, `undefined` and booleans so
+/// `{ ...(active && { color: "red" }) }` style conditions work
+fn js_value_to_static(value: &JsValue) -> Option<StaticValue> {
+    if let Some(value) = value.as_string() {
+        return Some(StaticValue::String(value));
+    }
+    if let Some(value) = value.as_f64() {
+        return Some(StaticValue::Number(value));
+    }
+    if js_sys::Array::is_array(value) {
+        let values = js_sys::Array::from(value)
+            .iter()
+            .filter_map(|value| js_value_to_static(&value))
+            .collect();
+        return Some(StaticValue::Array(values));
+    }
+    if value.is_object() && !value.is_function() {
+        let mut properties = vec![];
+        for entry in js_sys::Object::entries(value.unchecked_ref()).iter() {
+            let entry = js_sys::Array::from(&entry);
+            let (Some(key), Some(value)) =
+                (entry.get(0).as_string(), js_value_to_static(&entry.get(1)))
+            else {
+                continue;
+            };
+            set_property(&mut properties, key, value);
+        }
+        return Some(StaticValue::Object(properties));
+    }
+    None
+}
+
+/// makes the style object conversion available to evaluated programs
+pub(crate) fn register_object_to_css() {
+    let global = js_sys::global();
+    let name = JsValue::from_str(&object_to_css_function_name());
+    if js_sys::Reflect::has(&global, &name).unwrap_or_default() {
+        return;
+    }
+
+    let function = Closure::<dyn Fn(JsValue) -> Result<String, JsValue>>::new(|value: JsValue| {
+        match js_value_to_static(&value) {
+            Some(StaticValue::Object(properties)) => object_to_css(&properties)
+                .ok_or_else(|| JsValue::from_str("@style-this: unsupported value in style object")),
+            _ => Err(JsValue::from_str(
+                "@style-this: css() expects a style object",
+            )),
+        }
+    });
+    js_sys::Reflect::set(&global, &name, &function.into_js_value()).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string(value: &str) -> StaticValue {
+        StaticValue::String(value.to_string())
+    }
+
+    #[test]
+    fn test_property_name() {
+        assert_eq!(property_name("backgroundColor"), "background-color");
+        assert_eq!(property_name("WebkitBoxFlex"), "-webkit-box-flex");
+        assert_eq!(property_name("msTransition"), "-ms-transition");
+        assert_eq!(property_name("--mainColor"), "--mainColor");
+        assert_eq!(property_name("font-size"), "font-size");
+    }
+
+    #[test]
+    fn test_unitless_kebab_case() {
+        let css = object_to_css(&[
+            ("z-index".to_string(), StaticValue::Number(10.0)),
+            ("line-height".to_string(), StaticValue::Number(1.5)),
+            ("flex-grow".to_string(), StaticValue::Number(1.0)),
+            ("-webkit-line-clamp".to_string(), StaticValue::Number(2.0)),
+            ("WebkitLineClamp".to_string(), StaticValue::Number(3.0)),
+            ("font-size".to_string(), StaticValue::Number(12.0)),
+            ("-webkit-margin-start".to_string(), StaticValue::Number(4.0)),
+        ]);
+
+        assert_eq!(
+            css.unwrap(),
+            "z-index: 10;\nline-height: 1.5;\nflex-grow: 1;\n-webkit-line-clamp: 2;\n\
+             -webkit-line-clamp: 3;\nfont-size: 12px;\n-webkit-margin-start: 4px;"
+        );
+    }
+
+    #[test]
+    fn test_object_to_css() {
+        let css = object_to_css(&[
+            ("color".to_string(), string("red")),
+            ("padding".to_string(), StaticValue::Number(4.0)),
+            ("margin".to_string(), StaticValue::Number(0.0)),
+            ("lineHeight".to_string(), StaticValue::Number(1.5)),
+            ("--gap".to_string(), StaticValue::Number(2.0)),
+            (
+                "display".to_string(),
+                StaticValue::Array(vec![string("-webkit-box"), string("flex")]),
+            ),
+            (
+                "&:hover".to_string(),
+                StaticValue::Object(vec![("color".to_string(), string("blue"))]),
+            ),
+            (
+                "@media (min-width: 600px)".to_string(),
+                StaticValue::Object(vec![("fontSize".to_string(), StaticValue::Number(18.0))]),
+            ),
+        ]);
+
+        assert_eq!(
+            css.unwrap(),
+            "color: red;\npadding: 4px;\nmargin: 0;\nline-height: 1.5;\n--gap: 2;\n\
+             display: -webkit-box;\ndisplay: flex;\n&:hover {\ncolor: blue;\n}\n\
+             @media (min-width: 600px) {\nfont-size: 18px;\n}"
+        );
+    }
+}
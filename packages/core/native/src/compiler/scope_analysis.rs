@@ -0,0 +1,674 @@
+use crate::PREFIX;
+use crate::utils;
+use oxc_ast::ast::{
+    ArrowFunctionExpression, AwaitExpression, BindingIdentifier, BindingPatternKind,
+    CallExpression, Class, ExportDefaultDeclaration, Expression, ExpressionStatement, Function,
+    IdentifierReference, ImportExpression, NewExpression, Program, TaggedTemplateExpression,
+    ThisExpression, YieldExpression,
+};
+use oxc_ast::{AstBuilder, AstKind};
+use oxc_ast_visit::{Visit, VisitMut};
+use oxc_semantic::{AstNodes, NodeId, ScopeId, Scoping, SemanticBuilder, SymbolId};
+use oxc_span::{GetSpan, Span};
+use std::collections::{HashMap, HashSet};
+
+/// An identifier read by an expression, with the binding it resolves to, `None` for globals
+/// and identifiers the visitor created
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Reference {
+    pub name: String,
+    pub symbol: Option<SymbolId>,
+}
+
+impl Reference {
+    pub fn unresolved(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            symbol: None,
+        }
+    }
+}
+
+/// Bindings of a program as resolved by oxc's semantic analysis, which decides what css
+/// templates depend on and which of it is only known at runtime
+///
+/// Nodes are matched by span and name, so clones made by the visitor resolve the same way.
+#[derive(Default)]
+pub(crate) struct ScopeAnalysis {
+    scoping: Scoping,
+    /// span of each resolved identifier reference -> its binding
+    references: HashMap<Span, SymbolId>,
+    /// span of each binding identifier -> its binding
+    bindings: HashMap<Span, SymbolId>,
+    /// parameters, catch parameters, loop variables, hook results and locals derived from them
+    dynamic: HashSet<SymbolId>,
+    /// what the declaration of a binding reads, bindings holding style templates are left out
+    /// since the visitor references what their templates read itself
+    dependencies: HashMap<SymbolId, Vec<Reference>>,
+    /// span of each reassignment copied into the virtual program -> the bindings it writes
+    mutations: HashMap<Span, Vec<SymbolId>>,
+    /// bindings written where the write can't be copied, e.g. conditionally or in a function
+    /// -> span of the first such write
+    indeterminate_writes: HashMap<SymbolId, Span>,
+}
+
+impl ScopeAnalysis {
+    /// `is_style_call` tells whether a tag or callee path (`css`, `st.css`) is one the visitor
+    /// compiles
+    pub fn build(program: &Program, is_style_call: &dyn Fn(&str) -> bool) -> Self {
+        let semantic = SemanticBuilder::new().build(program).semantic;
+        let (scoping, nodes) = semantic.into_scoping_and_nodes();
+
+        let mut analysis = Self {
+            references: nodes
+                .iter()
+                .filter_map(|node| match node.kind() {
+                    AstKind::IdentifierReference(ident) => Some((
+                        ident.span,
+                        scoping.get_reference(ident.reference_id()).symbol_id()?,
+                    )),
+                    _ => None,
+                })
+                .collect(),
+            bindings: scoping
+                .symbol_ids()
+                .map(|symbol| (scoping.symbol_span(symbol), symbol))
+                .collect(),
+            scoping,
+            ..Default::default()
+        };
+
+        // top-level reassignments which run whenever the module does and only read values
+        // known at build time are copied into the virtual program along with the declaration,
+        // any other write leaves the value of the binding indeterminate
+        let root_scope = analysis.scoping.root_scope_id();
+        let mut copied_writes: HashMap<SymbolId, Vec<(&ExpressionStatement, Span)>> =
+            HashMap::new();
+        let mutated = analysis
+            .scoping
+            .symbol_ids()
+            .filter(|symbol| analysis.scoping.symbol_is_mutated(*symbol))
+            .collect::<Vec<_>>();
+        for symbol in mutated {
+            let writes = analysis
+                .scoping
+                .get_resolved_references(symbol)
+                .filter(|reference| reference.is_write())
+                .map(|reference| reference.node_id())
+                .collect::<Vec<_>>();
+            let top_level = analysis.scoping.symbol_scope_id(symbol) == root_scope;
+
+            for write in writes {
+                let span = nodes.kind(write).span();
+                match unconditional_statement(&nodes, write, root_scope) {
+                    Some(statement) if top_level && is_deterministic(statement) => {
+                        copied_writes.entry(symbol).or_default().push((statement, span));
+                    }
+                    _ => {
+                        analysis.indeterminate_writes.entry(symbol).or_insert(span);
+                    }
+                }
+            }
+        }
+
+        // `size = other * 2` is only known if `other` is
+        loop {
+            let mut changed = false;
+            for (symbol, writes) in &copied_writes {
+                if analysis.indeterminate_writes.contains_key(symbol) {
+                    continue;
+                }
+                let unknown_read = writes.iter().find(|(statement, _)| {
+                    analysis
+                        .statement_references(statement)
+                        .iter()
+                        .any(|reference| !analysis.is_known_at_build_time(reference))
+                });
+                if let Some((_, span)) = unknown_read {
+                    analysis.indeterminate_writes.insert(*symbol, *span);
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        let mut mutation_references: HashMap<SymbolId, Vec<Reference>> = HashMap::new();
+        for (symbol, writes) in copied_writes {
+            if analysis.indeterminate_writes.contains_key(&symbol) {
+                continue;
+            }
+            for (statement, _) in writes {
+                let references = analysis.statement_references(statement);
+                mutation_references
+                    .entry(symbol)
+                    .or_default()
+                    .extend(references);
+                analysis
+                    .mutations
+                    .entry(statement.span)
+                    .or_default()
+                    .push(symbol);
+            }
+        }
+
+        // locals are dynamic if what they are initialized with is, which can come later in
+        // the source, e.g. in hoisted functions
+        let mut derived = vec![];
+        for node in nodes.iter() {
+            match node.kind() {
+                AstKind::FormalParameter(parameter) => {
+                    analysis.mark_dynamic(&parameter.pattern.kind);
+                }
+                AstKind::CatchParameter(parameter) => {
+                    analysis.mark_dynamic(&parameter.pattern.kind);
+                }
+                AstKind::VariableDeclarator(declarator) => {
+                    let symbols = declarator
+                        .id
+                        .get_binding_identifiers()
+                        .iter()
+                        .map(|ident| ident.symbol_id())
+                        .collect::<Vec<_>>();
+
+                    let Some(init) = &declarator.init else {
+                        // `for (const item of items)`, only known while looping
+                        if matches!(
+                            nodes.parent_kind(nodes.parent_id(node.id())),
+                            AstKind::ForOfStatement(_) | AstKind::ForInStatement(_)
+                        ) {
+                            analysis.dynamic.extend(symbols);
+                        }
+                        continue;
+                    };
+
+                    let mut references = analysis.collect(declarator.span, |collector| {
+                        collector.visit_variable_declarator(declarator)
+                    });
+                    for symbol in &symbols {
+                        if let Some(mutation_references) = mutation_references.get(symbol) {
+                            references.extend(mutation_references.iter().cloned());
+                        }
+                    }
+                    if !is_style_call_expression(init, is_style_call) {
+                        for symbol in &symbols {
+                            analysis.dependencies.insert(*symbol, references.clone());
+                        }
+                    }
+
+                    if !symbols.is_empty()
+                        && symbols
+                            .iter()
+                            .all(|symbol| analysis.is_local_symbol(*symbol))
+                    {
+                        derived.push((symbols, references, is_hook_call(init)));
+                    }
+                }
+                AstKind::Function(function) if function.is_declaration() => {
+                    if let Some(id) = &function.id {
+                        let references = analysis.function_references(function);
+                        analysis.dependencies.insert(id.symbol_id(), references);
+                    }
+                }
+                AstKind::Class(class) if class.is_declaration() => {
+                    if let Some(id) = &class.id {
+                        let references = analysis.class_references(class);
+                        analysis.dependencies.insert(id.symbol_id(), references);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        loop {
+            let mut changed = false;
+            for (symbols, references, is_hook_call) in &derived {
+                if analysis.dynamic.contains(&symbols[0])
+                    || !(*is_hook_call || references.iter().any(|it| analysis.is_dynamic(it)))
+                {
+                    continue;
+                }
+                analysis.dynamic.extend(symbols);
+                changed = true;
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        analysis
+    }
+
+    fn mark_dynamic(&mut self, pattern: &BindingPatternKind) {
+        self.dynamic.extend(
+            pattern
+                .get_binding_identifiers()
+                .iter()
+                .map(|ident| ident.symbol_id()),
+        );
+    }
+
+    /// identifiers read within `span` which aren't declared within it
+    fn collect(&self, span: Span, visit: impl FnOnce(&mut ReferenceCollector)) -> Vec<Reference> {
+        let mut collector = ReferenceCollector::default();
+        visit(&mut collector);
+
+        collector
+            .references
+            .into_iter()
+            .map(|(name, reference_span)| {
+                let symbol = self.resolve(&self.references, &name, reference_span);
+                Reference { name, symbol }
+            })
+            .filter(|reference| {
+                reference.symbol.is_none_or(|symbol| {
+                    let declaration = self.scoping.symbol_span(symbol);
+                    !(span.start <= declaration.start && declaration.end <= span.end)
+                })
+            })
+            .collect()
+    }
+
+    fn resolve(&self, spans: &HashMap<Span, SymbolId>, name: &str, span: Span) -> Option<SymbolId> {
+        spans
+            .get(&span)
+            .copied()
+            .filter(|symbol| self.scoping.symbol_name(*symbol) == name)
+    }
+
+    pub fn expression_references(&self, expression: &Expression) -> Vec<Reference> {
+        self.collect(expression.span(), |collector| {
+            collector.visit_expression(expression)
+        })
+    }
+
+    pub fn tagged_template_references(&self, it: &TaggedTemplateExpression) -> Vec<Reference> {
+        self.collect(it.span, |collector| {
+            collector.visit_tagged_template_expression(it)
+        })
+    }
+
+    pub fn function_references(&self, function: &Function) -> Vec<Reference> {
+        self.collect(function.span, |collector| {
+            collector.visit_function(function, oxc_semantic::ScopeFlags::Function)
+        })
+    }
+
+    pub fn class_references(&self, class: &Class) -> Vec<Reference> {
+        self.collect(class.span, |collector| collector.visit_class(class))
+    }
+
+    pub fn statement_references(&self, it: &ExpressionStatement) -> Vec<Reference> {
+        self.collect(it.span, |collector| {
+            collector.visit_expression_statement(it)
+        })
+    }
+
+    pub fn export_default_references(&self, it: &ExportDefaultDeclaration) -> Vec<Reference> {
+        self.collect(it.span, |collector| {
+            collector.visit_export_default_declaration(it)
+        })
+    }
+
+    pub fn identifier_reference(&self, ident: &IdentifierReference) -> Reference {
+        Reference {
+            name: ident.name.to_string(),
+            symbol: self.resolve(&self.references, &ident.name, ident.span),
+        }
+    }
+
+    pub fn binding_reference(&self, ident: &BindingIdentifier) -> Reference {
+        Reference {
+            name: ident.name.to_string(),
+            symbol: self.resolve(&self.bindings, &ident.name, ident.span),
+        }
+    }
+
+    /// the top-level binding with that name
+    pub fn root_symbol(&self, name: &str) -> Option<SymbolId> {
+        self.scoping.get_root_binding(name)
+    }
+
+    pub fn is_dynamic(&self, reference: &Reference) -> bool {
+        reference
+            .symbol
+            .is_some_and(|symbol| self.dynamic.contains(&symbol))
+    }
+
+    pub fn is_dynamic_symbol(&self, symbol: SymbolId) -> bool {
+        self.dynamic.contains(&symbol)
+    }
+
+    /// whether the reference is to a binding declared below the top level
+    pub fn is_local(&self, reference: &Reference) -> bool {
+        reference
+            .symbol
+            .is_some_and(|symbol| self.is_local_symbol(symbol))
+    }
+
+    pub fn is_local_symbol(&self, symbol: SymbolId) -> bool {
+        self.scoping.symbol_scope_id(symbol) != self.scoping.root_scope_id()
+    }
+
+    pub fn symbol_name(&self, symbol: SymbolId) -> &str {
+        self.scoping.symbol_name(symbol)
+    }
+
+    /// bindings reassigned by the expression statement at `span`, which is copied into the
+    /// virtual program when any of them are referenced
+    pub fn mutated_symbols(&self, span: Span) -> &[SymbolId] {
+        self.mutations
+            .get(&span)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// top-level bindings whose value the virtual program reproduces, and the few globals
+    /// which can't be shadowed by assignments
+    fn is_known_at_build_time(&self, reference: &Reference) -> bool {
+        match reference.symbol {
+            Some(symbol) => {
+                !self.is_local_symbol(symbol) && !self.indeterminate_writes.contains_key(&symbol)
+            }
+            None => matches!(reference.name.as_str(), "undefined" | "NaN" | "Infinity"),
+        }
+    }
+
+    /// where a binding is reassigned in a way the virtual program can't reproduce
+    pub fn indeterminate_write(&self, symbol: SymbolId) -> Option<Span> {
+        self.indeterminate_writes.get(&symbol).copied()
+    }
+
+    pub fn dependencies(&self, symbol: SymbolId) -> &[Reference] {
+        self.dependencies
+            .get(&symbol)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// local bindings are renamed when they are hoisted into the virtual program, so they
+    /// don't clash with bindings of the same name in other scopes
+    pub fn alias(&self, symbol: SymbolId) -> Option<String> {
+        self.is_local_symbol(symbol).then(|| {
+            format!(
+                "{PREFIX}_var_{}_{}",
+                self.scoping.symbol_name(symbol),
+                symbol.index()
+            )
+        })
+    }
+
+    /// renames the local bindings and references to them in what it visits to their aliases
+    pub fn alias_replacer<'a, 'alloc>(
+        &'a self,
+        ast_builder: &'a AstBuilder<'alloc>,
+    ) -> AliasReplacer<'a, 'alloc> {
+        AliasReplacer {
+            analysis: self,
+            ast_builder,
+        }
+    }
+}
+
+#[derive(Default)]
+struct ReferenceCollector {
+    references: Vec<(String, Span)>,
+}
+
+impl<'a> Visit<'a> for ReferenceCollector {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        self.references.push((it.name.to_string(), it.span));
+    }
+}
+
+pub(crate) struct AliasReplacer<'a, 'alloc> {
+    analysis: &'a ScopeAnalysis,
+    ast_builder: &'a AstBuilder<'alloc>,
+}
+
+impl<'alloc> VisitMut<'alloc> for AliasReplacer<'_, 'alloc> {
+    fn visit_identifier_reference(&mut self, it: &mut IdentifierReference<'alloc>) {
+        if let Some(symbol) = self
+            .analysis
+            .resolve(&self.analysis.references, &it.name, it.span)
+            && let Some(alias) = self.analysis.alias(symbol)
+        {
+            it.name = self.ast_builder.atom(&alias);
+        }
+    }
+
+    fn visit_binding_identifier(&mut self, it: &mut BindingIdentifier<'alloc>) {
+        if let Some(symbol) = self
+            .analysis
+            .resolve(&self.analysis.bindings, &it.name, it.span)
+            && let Some(alias) = self.analysis.alias(symbol)
+        {
+            it.name = self.ast_builder.atom(&alias);
+        }
+    }
+}
+
+/// the expression statement containing a write when it runs whenever the block declaring the
+/// binding does, e.g. `size = 8;` but not `if (x) size = 8;` or a write in a function
+fn unconditional_statement<'a>(
+    nodes: &AstNodes<'a>,
+    write: NodeId,
+    scope: ScopeId,
+) -> Option<&'a ExpressionStatement<'a>> {
+    let statement = nodes.ancestor_ids(write).find(|id| {
+        matches!(
+            nodes.kind(*id),
+            AstKind::ExpressionStatement(_)
+                | AstKind::Function(_)
+                | AstKind::ArrowFunctionExpression(_)
+                | AstKind::Class(_)
+        )
+    })?;
+    let AstKind::ExpressionStatement(it) = nodes.kind(statement) else {
+        return None;
+    };
+
+    let in_statement_list = matches!(
+        nodes.parent_kind(statement),
+        AstKind::Program(_) | AstKind::FunctionBody(_) | AstKind::BlockStatement(_)
+    );
+    (in_statement_list && nodes.get_node(statement).scope_id() == scope).then_some(it)
+}
+
+/// whether the statement evaluates the same each time, without calls or anything else which
+/// can return a different value or have side effects
+fn is_deterministic(statement: &ExpressionStatement) -> bool {
+    struct Deterministic {
+        deterministic: bool,
+    }
+
+    impl<'a> Visit<'a> for Deterministic {
+        fn visit_call_expression(&mut self, _it: &CallExpression<'a>) {
+            self.deterministic = false;
+        }
+        fn visit_new_expression(&mut self, _it: &NewExpression<'a>) {
+            self.deterministic = false;
+        }
+        fn visit_await_expression(&mut self, _it: &AwaitExpression<'a>) {
+            self.deterministic = false;
+        }
+        fn visit_yield_expression(&mut self, _it: &YieldExpression<'a>) {
+            self.deterministic = false;
+        }
+        fn visit_tagged_template_expression(&mut self, _it: &TaggedTemplateExpression<'a>) {
+            self.deterministic = false;
+        }
+        fn visit_import_expression(&mut self, _it: &ImportExpression<'a>) {
+            self.deterministic = false;
+        }
+        fn visit_this_expression(&mut self, _it: &ThisExpression) {
+            self.deterministic = false;
+        }
+        fn visit_function(&mut self, _it: &Function<'a>, _flags: oxc_semantic::ScopeFlags) {
+            self.deterministic = false;
+        }
+        fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {
+            self.deterministic = false;
+        }
+        fn visit_class(&mut self, _it: &Class<'a>) {
+            self.deterministic = false;
+        }
+    }
+
+    let mut visitor = Deterministic {
+        deterministic: true,
+    };
+    visitor.visit_expression_statement(statement);
+    visitor.deterministic
+}
+
+/// `css`...``, `css({...})` and the other calls the visitor compiles
+fn is_style_call_expression(expression: &Expression, is_style_call: &dyn Fn(&str) -> bool) -> bool {
+    let callee = match expression.without_parentheses() {
+        Expression::TaggedTemplateExpression(template) => &template.tag,
+        Expression::CallExpression(call) => &call.callee,
+        _ => return false,
+    };
+    utils::expression_path(callee).is_some_and(|path| is_style_call(&path))
+}
+
+/// matches React hooks (`useState(...)`) and Solid primitives holding reactive state
+fn is_hook_call(expression: &Expression) -> bool {
+    let Expression::CallExpression(call) = expression.without_parentheses() else {
+        return false;
+    };
+    let Expression::Identifier(callee) = &call.callee else {
+        return false;
+    };
+    let name = callee.name.as_str();
+
+    name.strip_prefix("use")
+        .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()))
+        || matches!(
+            name,
+            "createSignal" | "createMemo" | "createStore" | "createResource"
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scope_analysis() {
+        let allocator = oxc_allocator::Allocator::default();
+        let code = r#"
+            import { css } from "@style-this/core";
+            const size = 4;
+            function Card(props) {
+                const [open] = useState(false);
+                const width = open ? size : 0;
+                const pad = size * 2;
+                try {} catch (error) {}
+                for (const item of props.items) {}
+                return double(pad);
+            }
+            function double(x) { return x * size; }
+            const cls = css`width: ${size}px;`;
+        "#;
+        let program = oxc_parser::Parser::new(&allocator, code, oxc_span::SourceType::tsx())
+            .parse()
+            .program;
+        let analysis = ScopeAnalysis::build(&program, &|path| path == "css");
+
+        let symbol = |name: &str| {
+            analysis
+                .scoping
+                .symbol_ids()
+                .find(|symbol| analysis.scoping.symbol_name(*symbol) == name)
+                .unwrap()
+        };
+        let names = |symbol| {
+            analysis
+                .dependencies(symbol)
+                .iter()
+                .map(|reference| reference.name.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        for name in ["props", "open", "width", "error", "item", "x"] {
+            assert!(analysis.is_dynamic_symbol(symbol(name)), "{name}");
+        }
+        for name in ["size", "pad", "Card", "double"] {
+            assert!(!analysis.is_dynamic_symbol(symbol(name)), "{name}");
+        }
+
+        assert_eq!(names(symbol("pad")), ["size"]);
+        assert_eq!(names(symbol("double")), ["size"]);
+        assert!(names(symbol("cls")).is_empty());
+
+        assert_eq!(analysis.alias(symbol("size")), None);
+        assert_eq!(
+            analysis.alias(symbol("pad")),
+            Some(format!("{PREFIX}_var_pad_{}", symbol("pad").index()))
+        );
+    }
+
+    #[test]
+    fn test_mutations() {
+        let allocator = oxc_allocator::Allocator::default();
+        let code = r#"
+            const base = 2;
+            let size = 4;
+            size = base * size;
+            let toggled = false;
+            if (x) toggled = true;
+            let count = 0;
+            function next() { count++; }
+            let random = 1;
+            random = Math.random();
+            let fetched = 1;
+            fetched = await fetchSize();
+            let computed = 1;
+            computed = compute();
+            let derived = 1;
+            derived = random * 2;
+            function compute() { let local = 1; local = 2; return local; }
+        "#;
+        let program = oxc_parser::Parser::new(&allocator, code, oxc_span::SourceType::tsx())
+            .parse()
+            .program;
+        let analysis = ScopeAnalysis::build(&program, &|path| path == "css");
+        let symbol = |name: &str| analysis.root_symbol(name).unwrap();
+
+        let span = analysis.mutations.keys().next().unwrap();
+        assert_eq!(analysis.mutations.len(), 1);
+        assert_eq!(
+            &code[span.start as usize..span.end as usize],
+            "size = base * size;"
+        );
+        assert_eq!(analysis.mutated_symbols(*span), [symbol("size")]);
+        assert!(
+            analysis
+                .dependencies(symbol("size"))
+                .iter()
+                .any(|reference| reference.name == "base")
+        );
+
+        assert_eq!(analysis.indeterminate_write(symbol("size")), None);
+        let local = analysis
+            .scoping
+            .symbol_ids()
+            .find(|symbol| analysis.scoping.symbol_name(*symbol) == "local")
+            .unwrap();
+        assert!(analysis.indeterminate_write(local).is_some());
+
+        for (name, write) in [
+            ("toggled", "toggled"),
+            ("count", "count"),
+            ("random", "random"),
+            ("fetched", "fetched"),
+            ("computed", "computed"),
+            ("derived", "derived"),
+        ] {
+            let span = analysis.indeterminate_write(symbol(name)).unwrap();
+            assert_eq!(&code[span.start as usize..span.end as usize], write);
+        }
+    }
+}
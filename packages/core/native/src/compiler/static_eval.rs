@@ -0,0 +1,532 @@
+use super::object_css::{object_to_css, object_to_css_function_name};
+use oxc_ast::ast::{
+    ArrayExpressionElement, BinaryOperator, BindingPatternKind, Declaration, Expression,
+    MemberExpression, ObjectPropertyKind, Program, PropertyKey, PropertyKind,
+    SimpleAssignmentTarget, SpreadElement, Statement, UnaryExpression, UnaryOperator,
+    VariableDeclaration, VariableDeclarationKind,
+};
+use oxc_ast_visit::{Visit, walk};
+use std::collections::{HashMap, HashSet};
+
+/// A value known at compile time, so css templates using it don't need JS evaluation
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum StaticValue {
+    String(String),
+    Number(f64),
+    Array(Vec<StaticValue>),
+    /// properties in insertion order, which matters for the generated CSS
+    Object(Vec<(String, StaticValue)>),
+}
+
+impl StaticValue {
+    /// converts the value the same way JS does when interpolating it into a template literal
+    pub fn to_js_string(&self) -> Option<String> {
+        match self {
+            StaticValue::String(value) => Some(value.clone()),
+            StaticValue::Number(value) => js_number_to_string(*value),
+            StaticValue::Array(values) => values
+                .iter()
+                .map(StaticValue::to_js_string)
+                .collect::<Option<Vec<_>>>()
+                .map(|values| values.join(",")),
+            // "[object Object]" is never what the user wants in CSS
+            StaticValue::Object(_) => None,
+        }
+    }
+
+    fn get(&self, key: &StaticValue) -> Option<StaticValue> {
+        match (self, key) {
+            (StaticValue::Object(object), key) => {
+                let key = key.to_js_string()?;
+                object
+                    .iter()
+                    .find(|(name, _)| *name == key)
+                    .map(|(_, value)| value.clone())
+            }
+            (StaticValue::Array(values), StaticValue::Number(index))
+                if index.fract() == 0.0 && *index >= 0.0 =>
+            {
+                values.get(*index as usize).cloned()
+            }
+            (StaticValue::Array(values), StaticValue::String(key)) if key == "length" => {
+                Some(StaticValue::Number(values.len() as f64))
+            }
+            (StaticValue::String(value), StaticValue::String(key)) if key == "length" => {
+                Some(StaticValue::Number(value.encode_utf16().count() as f64))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// sets an object property, keeping the position of an existing key like JS objects do
+pub(crate) fn set_property(
+    object: &mut Vec<(String, StaticValue)>,
+    key: String,
+    value: StaticValue,
+) {
+    match object.iter_mut().find(|(name, _)| *name == key) {
+        Some((_, existing)) => *existing = value,
+        None => object.push((key, value)),
+    }
+}
+
+/// formats numbers like `Number.prototype.toString`, giving up where Rust and JS formatting differ
+pub(crate) fn js_number_to_string(value: f64) -> Option<String> {
+    if !value.is_finite() || value.abs() >= 1e21 || (value != 0.0 && value.abs() < 1e-6) {
+        return None;
+    }
+    // `-0` prints as "0" in JS, other values match Rust's shortest round-trip formatting
+    if value == 0.0 {
+        return Some("0".to_string());
+    }
+    Some(format!("{value}"))
+}
+
+/// collects top-level `const` declarations whose values can be folded at compile time
+pub(crate) fn collect_static_consts(program: &Program) -> HashMap<String, StaticValue> {
+    // `const theme = {...}; theme.a = "blue";` is only known after running the program
+    let mut member_writes = MemberWrites::default();
+    member_writes.visit_program(program);
+    let mut excluded = member_writes.0;
+
+    // objects and arrays are shared, `Object.assign(theme, ...)`, `theme.list.push(...)` or
+    // `const t = theme; t.a = ...` change them without writing to a member of the const
+    loop {
+        let consts = fold_consts(program, &excluded);
+        let mut escaping = EscapingObjects {
+            consts: &consts,
+            escaped: HashSet::new(),
+        };
+        escaping.visit_program(program);
+        if escaping.escaped.is_empty() {
+            return consts;
+        }
+        // consts folded from the escaped ones are folded again without them
+        excluded.extend(escaping.escaped);
+    }
+}
+
+fn fold_consts(program: &Program, excluded: &HashSet<String>) -> HashMap<String, StaticValue> {
+    let mut consts = HashMap::new();
+
+    let mut collect = |declaration: &VariableDeclaration| {
+        if declaration.kind != VariableDeclarationKind::Const {
+            return;
+        }
+        for declarator in &declaration.declarations {
+            let (BindingPatternKind::BindingIdentifier(ident), Some(init)) =
+                (&declarator.id.kind, &declarator.init)
+            else {
+                continue;
+            };
+            if excluded.contains(ident.name.as_str()) {
+                continue;
+            }
+            if let Some(value) = fold_expression(init, &|name| consts.get(name).cloned()) {
+                consts.insert(ident.name.to_string(), value);
+            }
+        }
+    };
+
+    for stmt in &program.body {
+        match stmt {
+            Statement::VariableDeclaration(declaration) => collect(declaration),
+            Statement::ExportNamedDeclaration(export) => {
+                if let Some(Declaration::VariableDeclaration(declaration)) = &export.declaration {
+                    collect(declaration);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    consts
+}
+
+/// Object and array consts used other than by reading a string or number out of them, or by
+/// spreading a copy without nested objects
+struct EscapingObjects<'c> {
+    consts: &'c HashMap<String, StaticValue>,
+    escaped: HashSet<String>,
+}
+
+impl EscapingObjects<'_> {
+    /// the object or array const an expression like `theme.colors[key]` reads from
+    fn object_root<'e>(&self, expression: &'e Expression<'e>) -> Option<&'e str> {
+        let mut expression = expression.get_inner_expression();
+        while let Some(member) = expression.as_member_expression() {
+            expression = member.object().get_inner_expression();
+        }
+        let Expression::Identifier(ident) = expression else {
+            return None;
+        };
+        matches!(
+            self.consts.get(ident.name.as_str()),
+            Some(StaticValue::Object(_) | StaticValue::Array(_))
+        )
+        .then_some(ident.name.as_str())
+    }
+
+    fn fold(&self, expression: &Expression) -> Option<StaticValue> {
+        fold_expression(expression, &|name| self.consts.get(name).cloned())
+    }
+
+    /// visits the computed keys of a member chain without visiting the chain itself
+    fn visit_computed_keys<'a>(&mut self, expression: &Expression<'a>) {
+        let mut expression = expression.get_inner_expression();
+        while let Some(member) = expression.as_member_expression() {
+            if let MemberExpression::ComputedMemberExpression(computed) = member {
+                self.visit_expression(&computed.expression);
+            }
+            expression = member.object().get_inner_expression();
+        }
+    }
+}
+
+impl<'a> Visit<'a> for EscapingObjects<'_> {
+    fn visit_expression(&mut self, it: &Expression<'a>) {
+        let Some(root) = self.object_root(it) else {
+            walk::walk_expression(self, it);
+            return;
+        };
+        if !matches!(
+            self.fold(it),
+            Some(StaticValue::String(_) | StaticValue::Number(_))
+        ) {
+            self.escaped.insert(root.to_string());
+        }
+        self.visit_computed_keys(it);
+    }
+
+    fn visit_spread_element(&mut self, it: &SpreadElement<'a>) {
+        let is_flat_copy = self.object_root(&it.argument).is_some()
+            && match self.fold(&it.argument) {
+                Some(StaticValue::Object(properties)) => properties
+                    .iter()
+                    .all(|(_, value)| !matches!(value, StaticValue::Object(_) | StaticValue::Array(_))),
+                Some(StaticValue::Array(values)) => values
+                    .iter()
+                    .all(|value| !matches!(value, StaticValue::Object(_) | StaticValue::Array(_))),
+                _ => false,
+            };
+        if is_flat_copy {
+            self.visit_computed_keys(&it.argument);
+        } else {
+            walk::walk_spread_element(self, it);
+        }
+    }
+}
+
+/// names of identifiers whose members are assigned, updated or deleted anywhere in the program
+#[derive(Default)]
+struct MemberWrites(HashSet<String>);
+
+impl MemberWrites {
+    fn insert_root(&mut self, member: &MemberExpression) {
+        let mut object = member.object();
+        loop {
+            match object.get_inner_expression() {
+                Expression::Identifier(ident) => {
+                    self.0.insert(ident.name.to_string());
+                    return;
+                }
+                expression => match expression.as_member_expression() {
+                    Some(member) => object = member.object(),
+                    None => return,
+                },
+            }
+        }
+    }
+}
+
+impl<'a> Visit<'a> for MemberWrites {
+    fn visit_simple_assignment_target(&mut self, it: &SimpleAssignmentTarget<'a>) {
+        if let Some(member) = it.as_member_expression() {
+            self.insert_root(member);
+        }
+        walk::walk_simple_assignment_target(self, it);
+    }
+
+    fn visit_unary_expression(&mut self, it: &UnaryExpression<'a>) {
+        if it.operator == UnaryOperator::Delete
+            && let Some(member) = it.argument.get_inner_expression().as_member_expression()
+        {
+            self.insert_root(member);
+        }
+        walk::walk_unary_expression(self, it);
+    }
+}
+
+/// folds literals, template literals, arithmetic and member access on known values
+pub(crate) fn fold_expression(
+    expression: &Expression,
+    resolve: &dyn Fn(&str) -> Option<StaticValue>,
+) -> Option<StaticValue> {
+    match expression {
+        Expression::StringLiteral(literal) => Some(StaticValue::String(literal.value.to_string())),
+        Expression::NumericLiteral(literal) => Some(StaticValue::Number(literal.value)),
+        Expression::TemplateLiteral(template) => {
+            let mut ret = String::new();
+            for (idx, quasi) in template.quasis.iter().enumerate() {
+                ret.push_str(quasi.value.cooked.as_ref()?);
+                if let Some(expression) = template.expressions.get(idx) {
+                    ret.push_str(&fold_expression(expression, resolve)?.to_js_string()?);
+                }
+            }
+            Some(StaticValue::String(ret))
+        }
+        Expression::Identifier(ident) => resolve(&ident.name),
+        // `css({...})` calls, see `VisitorTransformer::convert_css_object_call`
+        Expression::CallExpression(call)
+            if call.arguments.len() == 1
+                && matches!(&call.callee, Expression::Identifier(callee) if callee.name == object_to_css_function_name()) =>
+        {
+            let StaticValue::Object(properties) =
+                fold_expression(call.arguments[0].as_expression()?, resolve)?
+            else {
+                return None;
+            };
+            object_to_css(&properties).map(StaticValue::String)
+        }
+        Expression::ParenthesizedExpression(expression) => {
+            fold_expression(&expression.expression, resolve)
+        }
+        Expression::TSAsExpression(expression) => fold_expression(&expression.expression, resolve),
+        Expression::TSSatisfiesExpression(expression) => {
+            fold_expression(&expression.expression, resolve)
+        }
+        Expression::TSNonNullExpression(expression) => {
+            fold_expression(&expression.expression, resolve)
+        }
+        Expression::UnaryExpression(expression) => {
+            let StaticValue::Number(value) = fold_expression(&expression.argument, resolve)? else {
+                return None;
+            };
+            match expression.operator {
+                UnaryOperator::UnaryNegation => Some(StaticValue::Number(-value)),
+                UnaryOperator::UnaryPlus => Some(StaticValue::Number(value)),
+                _ => None,
+            }
+        }
+        Expression::BinaryExpression(expression) => {
+            let left = fold_expression(&expression.left, resolve)?;
+            let right = fold_expression(&expression.right, resolve)?;
+
+            if let (StaticValue::Number(left), StaticValue::Number(right)) = (&left, &right) {
+                let value = match expression.operator {
+                    BinaryOperator::Addition => left + right,
+                    BinaryOperator::Subtraction => left - right,
+                    BinaryOperator::Multiplication => left * right,
+                    BinaryOperator::Division => left / right,
+                    BinaryOperator::Remainder => left % right,
+                    BinaryOperator::Exponential => left.powf(*right),
+                    _ => return None,
+                };
+                return Some(StaticValue::Number(value));
+            }
+
+            if expression.operator == BinaryOperator::Addition {
+                return Some(StaticValue::String(format!(
+                    "{}{}",
+                    left.to_js_string()?,
+                    right.to_js_string()?
+                )));
+            }
+
+            None
+        }
+        Expression::StaticMemberExpression(expression) => {
+            fold_expression(&expression.object, resolve)?
+                .get(&StaticValue::String(expression.property.name.to_string()))
+        }
+        Expression::ComputedMemberExpression(expression) => {
+            let key = fold_expression(&expression.expression, resolve)?;
+            fold_expression(&expression.object, resolve)?.get(&key)
+        }
+        Expression::ArrayExpression(array) => array
+            .elements
+            .iter()
+            .map(|element| match element {
+                ArrayExpressionElement::SpreadElement(_) | ArrayExpressionElement::Elision(_) => {
+                    None
+                }
+                element => fold_expression(element.to_expression(), resolve),
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(StaticValue::Array),
+        Expression::ObjectExpression(object) => {
+            let mut ret = vec![];
+            for property in &object.properties {
+                match property {
+                    ObjectPropertyKind::ObjectProperty(property) => {
+                        if property.kind != PropertyKind::Init || property.method {
+                            return None;
+                        }
+                        let key = match &property.key {
+                            PropertyKey::StaticIdentifier(ident) => ident.name.to_string(),
+                            PropertyKey::StringLiteral(literal) => literal.value.to_string(),
+                            PropertyKey::NumericLiteral(literal) => {
+                                js_number_to_string(literal.value)?
+                            }
+                            _ => return None,
+                        };
+                        set_property(&mut ret, key, fold_expression(&property.value, resolve)?);
+                    }
+                    ObjectPropertyKind::SpreadProperty(spread) => {
+                        let StaticValue::Object(object) =
+                            fold_expression(&spread.argument, resolve)?
+                        else {
+                            return None;
+                        };
+                        for (key, value) in object {
+                            set_property(&mut ret, key, value);
+                        }
+                    }
+                }
+            }
+            Some(StaticValue::Object(ret))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    fn fold(code: &str) -> Option<String> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, code, SourceType::ts())
+            .parse()
+            .program;
+        let consts = collect_static_consts(&program);
+
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.last() else {
+            panic!("expected an expression statement");
+        };
+        fold_expression(&stmt.expression, &|name| consts.get(name).cloned())?.to_js_string()
+    }
+
+    #[test]
+    fn test_fold_literals() {
+        assert_eq!(fold("('red')"), Some("red".to_string()));
+        assert_eq!(fold("`${4 * 2}px`"), Some("8px".to_string()));
+        assert_eq!(fold("1.5 + 'rem'"), Some("1.5rem".to_string()));
+        assert_eq!(fold("-(10 / 4)"), Some("-2.5".to_string()));
+        assert_eq!(fold("[1, 'a']"), Some("1,a".to_string()));
+        assert_eq!(fold("1e20"), Some("100000000000000000000".to_string()));
+        assert_eq!(fold("2 ** 60"), Some("1152921504606847000".to_string()));
+        assert_eq!(fold("-1e20"), Some("-100000000000000000000".to_string()));
+        assert_eq!(fold("-0"), Some("0".to_string()));
+    }
+
+    #[test]
+    fn test_fold_consts() {
+        let code = r#"
+            const base = 4;
+            export const theme = {
+                colors: { primary: "tomato", "on-primary": "white" },
+                space: [0, base, base * 2],
+            } as const;
+            const merged = { ...theme.colors, accent: `${theme.colors.primary}` };
+        "#;
+
+        assert_eq!(
+            fold(&format!("{code} theme.colors.primary")),
+            Some("tomato".to_string())
+        );
+        assert_eq!(
+            fold(&format!("{code} theme.colors['on-primary']")),
+            Some("white".to_string())
+        );
+        assert_eq!(
+            fold(&format!("{code} `${{theme.space[2]}}px`")),
+            Some("8px".to_string())
+        );
+        assert_eq!(
+            fold(&format!("{code} merged.accent")),
+            Some("tomato".to_string())
+        );
+    }
+
+    #[test]
+    fn test_fold_style_object() {
+        let code = r#"
+            const size = 4;
+            __styleThis_objectToCss({ paddingTop: size * 2, "&:hover": { opacity: 0.5 } })
+        "#;
+
+        assert_eq!(
+            fold(code),
+            Some("padding-top: 8px;\n&:hover {\nopacity: 0.5;\n}".to_string())
+        );
+    }
+
+    #[test]
+    fn test_fold_dynamic() {
+        assert_eq!(fold("let a = 'red'; a"), None);
+        assert_eq!(fold("const a = Math.random(); a"), None);
+        assert_eq!(fold("const a = { b: 1 }; a"), None);
+        assert_eq!(fold("const a = { b: 1 }; a.c"), None);
+        assert_eq!(fold("const a = { get b() { return 1 } }; a.b"), None);
+        assert_eq!(fold("1 / 0"), None);
+        assert_eq!(fold("props.color"), None);
+    }
+
+    #[test]
+    fn test_fold_mutated_consts() {
+        assert_eq!(fold("const a = { b: 'red' }; a.b = 'blue'; a.b"), None);
+        assert_eq!(fold("const a = { b: { c: 1 } }; a.b['c'] += 1; a.b.c"), None);
+        assert_eq!(fold("const a = { b: 1 }; a.b++; a.b"), None);
+        assert_eq!(fold("const a = { b: 1 }; delete a.b; a.b"), None);
+        assert_eq!(fold("const a = [1]; [a[0]] = [2]; a[0]"), None);
+        assert_eq!(
+            fold("const a = { b: 1 }; function f() { a.b = 2 } a.b"),
+            None
+        );
+        // writes to other bindings don't matter
+        assert_eq!(
+            fold("const a = { b: 'red' }; other.b = 'blue'; a.b"),
+            Some("red".to_string())
+        );
+    }
+
+    #[test]
+    fn test_fold_escaping_objects() {
+        assert_eq!(
+            fold("const a = { b: 'red' }; Object.assign(a, { b: 'blue' }); a.b"),
+            None
+        );
+        assert_eq!(fold("const a = { b: 'red' }; const t = a; t.b = 'blue'; a.b"), None);
+        assert_eq!(
+            fold("const a = { b: { c: 'red' } }; const t = a.b; t.c = 'blue'; a.b.c"),
+            None
+        );
+        assert_eq!(fold("const a = { list: [1] }; a.list.push(2); a.list[0]"), None);
+        assert_eq!(fold("const a = { b: 'red' }; paint(a); a.b"), None);
+        assert_eq!(fold("const a = { b: 'red' }; paint(a); const c = a.b; c"), None);
+        // copies with nested objects share them
+        assert_eq!(
+            fold("const a = { b: { c: 'red' } }; const d = { ...a }; d.b.c = 'blue'; a.b.c"),
+            None
+        );
+
+        // reading values out of them and flat copies don't
+        assert_eq!(
+            fold("const a = { b: 'red', n: [1, 2] }; log(a.b, a.n[1], a.n.length); a.b"),
+            Some("red".to_string())
+        );
+        assert_eq!(
+            fold("const a = { b: 'red' }; const d = { ...a, e: 1 }; d.b"),
+            Some("red".to_string())
+        );
+        assert_eq!(
+            fold("const size = 4; size.toFixed(2); `${size}px`"),
+            Some("4px".to_string())
+        );
+    }
+}
@@ -0,0 +1,342 @@
+use oxc_allocator::{Allocator, CloneIn};
+use oxc_ast::AstBuilder;
+use oxc_ast::ast::{Expression, PropertyKey, PropertyKind, TemplateElement, TemplateElementValue};
+use oxc_span::Span;
+
+/// A piece of a `style` template, literal text or the interpolation at an index
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum StylePart {
+    Text(String),
+    Expression(usize),
+}
+
+/// An entry of the object a `style` template compiles to for React
+#[derive(Debug, PartialEq)]
+pub(crate) enum StyleEntry {
+    /// `background-color: ${color}` -> `backgroundColor: color`
+    Declaration {
+        property: String,
+        value: Vec<StylePart>,
+    },
+    /// an interpolation on its own, e.g. another style object, spread into the object
+    Spread(usize),
+}
+
+/// splits a `style` template into declarations, errors on what a style object can't hold
+/// (nested rules, interpolated property names)
+pub(crate) fn parse_style_template(parts: Vec<StylePart>) -> Result<Vec<StyleEntry>, String> {
+    let mut declarations = vec![];
+    let mut current = vec![];
+    let mut quote = None;
+    let mut parens = 0usize;
+    let mut in_comment = false;
+
+    for part in parts {
+        let text = match part {
+            StylePart::Text(text) => text,
+            expression => {
+                current.push(expression);
+                continue;
+            }
+        };
+
+        let mut buffer = String::new();
+        let mut chars = text.chars().peekable();
+        while let Some(ch) = chars.next() {
+            if in_comment {
+                if ch == '*' && chars.next_if_eq(&'/').is_some() {
+                    in_comment = false;
+                }
+                continue;
+            }
+
+            match (ch, quote) {
+                ('\\', Some(_)) => {
+                    buffer.push(ch);
+                    if let Some(escaped) = chars.next() {
+                        buffer.push(escaped);
+                    }
+                    continue;
+                }
+                (ch, Some(open)) if ch == open => quote = None,
+                (_, Some(_)) => {}
+                ('"' | '\'', None) => quote = Some(ch),
+                ('/', None) if chars.next_if_eq(&'*').is_some() => {
+                    in_comment = true;
+                    continue;
+                }
+                ('(', None) => parens += 1,
+                (')', None) => parens = parens.saturating_sub(1),
+                ('{' | '}', None) => {
+                    return Err("nested rules can't be compiled to a style object".to_string());
+                }
+                (';', None) if parens == 0 => {
+                    push_text(&mut current, std::mem::take(&mut buffer));
+                    declarations.push(std::mem::take(&mut current));
+                    continue;
+                }
+                _ => {}
+            }
+            buffer.push(ch);
+        }
+        push_text(&mut current, buffer);
+    }
+    declarations.push(current);
+
+    let mut entries = vec![];
+    for parts in declarations {
+        parse_declaration(parts, &mut entries)?;
+    }
+    Ok(entries)
+}
+
+fn parse_declaration(parts: Vec<StylePart>, entries: &mut Vec<StyleEntry>) -> Result<(), String> {
+    let mut parts = trim_parts(parts);
+
+    // interpolations on a line of their own don't need a semicolon
+    while let [StylePart::Expression(index), StylePart::Text(text), ..] = parts.as_slice()
+        && text
+            .trim_start_matches([' ', '\t'])
+            .starts_with(['\n', '\r'])
+    {
+        entries.push(StyleEntry::Spread(*index));
+        parts = trim_parts(parts.split_off(1));
+    }
+
+    let (property, value) = match parts.first() {
+        None => return Ok(()),
+        Some(StylePart::Expression(index)) if parts.len() == 1 => {
+            entries.push(StyleEntry::Spread(*index));
+            return Ok(());
+        }
+        Some(StylePart::Text(text)) if text.contains(':') => {
+            let (property, value) = text.split_once(':').unwrap();
+            (property.trim().to_string(), value.to_string())
+        }
+        _ => {
+            return Err(
+                "style object properties have to be written out, e.g. `color: ${value}`"
+                    .to_string(),
+            );
+        }
+    };
+
+    if property.is_empty() || property.contains(char::is_whitespace) {
+        return Err(format!("invalid style property '{property}'"));
+    }
+
+    parts[0] = StylePart::Text(value);
+    let value = trim_parts(parts);
+    if value.is_empty() {
+        return Err(format!("missing value for style property '{property}'"));
+    }
+
+    entries.push(StyleEntry::Declaration {
+        property: property_key(&property),
+        value,
+    });
+    Ok(())
+}
+
+/// appends text, merging it into the previous text part
+fn push_text(parts: &mut Vec<StylePart>, text: String) {
+    if text.is_empty() {
+        return;
+    }
+    match parts.last_mut() {
+        Some(StylePart::Text(last)) => last.push_str(&text),
+        _ => parts.push(StylePart::Text(text)),
+    }
+}
+
+/// removes surrounding whitespace and the text parts left empty by it
+fn trim_parts(mut parts: Vec<StylePart>) -> Vec<StylePart> {
+    if let Some(StylePart::Text(text)) = parts.first_mut() {
+        *text = text.trim_start().to_string();
+    }
+    if let Some(StylePart::Text(text)) = parts.last_mut() {
+        *text = text.trim_end().to_string();
+    }
+    parts.retain(|part| !matches!(part, StylePart::Text(text) if text.is_empty()));
+    parts
+}
+
+/// `background-color` -> `backgroundColor`, `-webkit-box-flex` -> `WebkitBoxFlex`,
+/// `-ms-transform` -> `msTransform`, custom properties stay as they are
+fn property_key(property: &str) -> String {
+    if property.starts_with("--") {
+        return property.to_string();
+    }
+
+    let property = property.to_ascii_lowercase();
+    let property = match property.strip_prefix("-ms-") {
+        Some(rest) => format!("ms-{rest}"),
+        None => property,
+    };
+
+    let mut ret = String::with_capacity(property.len());
+    let mut uppercase = false;
+    for ch in property.chars() {
+        if ch == '-' {
+            uppercase = true;
+        } else if uppercase {
+            ret.push(ch.to_ascii_uppercase());
+            uppercase = false;
+        } else {
+            ret.push(ch);
+        }
+    }
+    ret
+}
+
+/// builds `{ backgroundColor: color, width: `${width}px`, ...base }`, interpolations are
+/// cloned from the template's `expressions`
+pub(crate) fn build_style_object<'alloc>(
+    ast_builder: &AstBuilder<'alloc>,
+    allocator: &'alloc Allocator,
+    span: Span,
+    entries: Vec<StyleEntry>,
+    expressions: &[Expression<'alloc>],
+) -> Expression<'alloc> {
+    let properties = entries.into_iter().map(|entry| match entry {
+        StyleEntry::Declaration { property, value } => {
+            let key = if property.starts_with("--") {
+                ast_builder
+                    .expression_string_literal(span, ast_builder.atom(&property), None)
+                    .into()
+            } else {
+                PropertyKey::StaticIdentifier(
+                    ast_builder.alloc_identifier_name(span, ast_builder.atom(&property)),
+                )
+            };
+
+            ast_builder.object_property_kind_object_property(
+                span,
+                PropertyKind::Init,
+                key,
+                build_value(ast_builder, allocator, span, value, expressions),
+                false,
+                false,
+                false,
+            )
+        }
+        StyleEntry::Spread(index) => ast_builder
+            .object_property_kind_spread_property(span, expressions[index].clone_in(allocator)),
+    });
+
+    Expression::ObjectExpression(
+        ast_builder.alloc_object_expression(span, ast_builder.vec_from_iter(properties)),
+    )
+}
+
+/// a string for static values, the interpolation itself when it's the whole value and a
+/// template literal otherwise
+fn build_value<'alloc>(
+    ast_builder: &AstBuilder<'alloc>,
+    allocator: &'alloc Allocator,
+    span: Span,
+    value: Vec<StylePart>,
+    expressions: &[Expression<'alloc>],
+) -> Expression<'alloc> {
+    match value.as_slice() {
+        [StylePart::Text(text)] => {
+            return ast_builder.expression_string_literal(span, ast_builder.atom(text), None);
+        }
+        [StylePart::Expression(index)] => return expressions[*index].clone_in(allocator),
+        _ => {}
+    }
+
+    let quasi = |text: &str, tail| TemplateElement {
+        span,
+        tail,
+        value: TemplateElementValue {
+            raw: ast_builder.atom(&escape_template_text(text)),
+            cooked: Some(ast_builder.atom(text)),
+        },
+        lone_surrogates: false,
+    };
+
+    let mut quasis = ast_builder.vec();
+    let mut template_expressions = ast_builder.vec();
+    let mut text = String::new();
+    for part in value {
+        match part {
+            StylePart::Text(part) => text.push_str(&part),
+            StylePart::Expression(index) => {
+                quasis.push(quasi(&std::mem::take(&mut text), false));
+                template_expressions.push(expressions[index].clone_in(allocator));
+            }
+        }
+    }
+    quasis.push(quasi(&text, true));
+
+    ast_builder.expression_template_literal(span, quasis, template_expressions)
+}
+
+fn escape_template_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('`', "\\`")
+        .replace("${", "\\${")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(text: &str) -> StylePart {
+        StylePart::Text(text.to_string())
+    }
+
+    fn declaration(property: &str, value: Vec<StylePart>) -> StyleEntry {
+        StyleEntry::Declaration {
+            property: property.to_string(),
+            value,
+        }
+    }
+
+    #[test]
+    fn test_parse_style_template() {
+        // style`
+        //   background-color: red;
+        //   width: ${width}px; /* comment; */
+        //   ${base}
+        //   --accent: ${accent};
+        //   background: url("a;b.png")
+        // `
+        let entries = parse_style_template(vec![
+            text("\n  background-color: red;\n  width: "),
+            StylePart::Expression(0),
+            text("px; /* comment; */\n  "),
+            StylePart::Expression(1),
+            text("\n  --accent: "),
+            StylePart::Expression(2),
+            text(";\n  background: url(\"a;b.png\")\n"),
+        ]);
+
+        assert_eq!(
+            entries,
+            Ok(vec![
+                declaration("backgroundColor", vec![text("red")]),
+                declaration("width", vec![StylePart::Expression(0), text("px")]),
+                StyleEntry::Spread(1),
+                declaration("--accent", vec![StylePart::Expression(2)]),
+                declaration("background", vec![text("url(\"a;b.png\")")]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_style_template_errors() {
+        assert!(parse_style_template(vec![text("&:hover { color: red; }")]).is_err());
+        assert!(parse_style_template(vec![StylePart::Expression(0), text(": red;")]).is_err());
+        assert!(parse_style_template(vec![text("color: ;")]).is_err());
+    }
+
+    #[test]
+    fn test_property_key() {
+        assert_eq!(property_key("font-size"), "fontSize");
+        assert_eq!(property_key("-webkit-box-flex"), "WebkitBoxFlex");
+        assert_eq!(property_key("-ms-transform"), "msTransform");
+        assert_eq!(property_key("--Brand-color"), "--Brand-color");
+    }
+}
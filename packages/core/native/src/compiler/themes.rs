@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+/// A theme variant from the `themes` option, evaluating styled files with some modules swapped
+#[derive(Debug, Clone)]
+pub(crate) struct Theme {
+    pub name: String,
+    /// class the theme's CSS is scoped under, `theme-<name>` by default
+    pub class_name: String,
+    /// import specifier or resolved file path -> module loaded instead
+    modules: HashMap<String, String>,
+}
+
+impl Theme {
+    /// the module to load instead of an import, matched by specifier or resolved path
+    pub fn replacement(&self, module_id: &str, filepath: &str) -> Option<&str> {
+        self.modules
+            .get(module_id)
+            .or_else(|| self.modules.get(filepath))
+            .map(String::as_str)
+    }
+
+    /// key of the values a module evaluated to under this theme
+    pub fn cache_key(theme: Option<&Theme>, filepath: &str) -> String {
+        match theme {
+            Some(theme) => format!("{filepath}?theme={}", theme.name),
+            None => filepath.to_string(),
+        }
+    }
+
+    /// nests the CSS evaluated for this theme under the theme class
+    pub fn scope_css(&self, css: &str, wrap_selectors_with_global: bool) -> String {
+        if wrap_selectors_with_global {
+            format!(":global(.{}) {{\n{css}\n}}", self.class_name)
+        } else {
+            format!(".{} {{\n{css}\n}}", self.class_name)
+        }
+    }
+
+    fn from_js(value: &JsValue) -> Option<Self> {
+        let get = |key: &str| js_sys::Reflect::get(value, &JsValue::from_str(key)).ok();
+
+        let name = get("name")?.as_string()?;
+        let class_name = get("className")
+            .and_then(|class_name| class_name.as_string())
+            .unwrap_or_else(|| format!("theme-{name}"));
+
+        let modules = get("modules")
+            .and_then(|modules| modules.dyn_into::<js_sys::Object>().ok())
+            .map(|modules| {
+                js_sys::Object::entries(&modules)
+                    .iter()
+                    .filter_map(|entry| {
+                        let entry = js_sys::Array::from(&entry);
+                        Some((entry.get(0).as_string()?, entry.get(1).as_string()?))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(Self {
+            name,
+            class_name,
+            modules,
+        })
+    }
+}
+
+/// reads the `themes` option, `[{ name, className?, modules: { [moduleId]: replacement } }]`
+pub(crate) fn parse_themes(opts: &JsValue) -> Vec<Theme> {
+    js_sys::Reflect::get(opts, &JsValue::from_str("themes"))
+        .ok()
+        .filter(js_sys::Array::is_array)
+        .map(|themes| {
+            js_sys::Array::from(&themes)
+                .iter()
+                .filter_map(|theme| Theme::from_js(&theme))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dark() -> Theme {
+        Theme {
+            name: "dark".to_string(),
+            class_name: "theme-dark".to_string(),
+            modules: HashMap::from([
+                ("./tokens/light".to_string(), "./tokens/dark".to_string()),
+                (
+                    "/project/src/palette.ts".to_string(),
+                    "/project/src/palette.dark.ts".to_string(),
+                ),
+            ]),
+        }
+    }
+
+    #[test]
+    fn test_replacement() {
+        let theme = dark();
+        assert_eq!(
+            theme.replacement("./tokens/light", "/project/src/tokens/light.ts"),
+            Some("./tokens/dark")
+        );
+        assert_eq!(
+            theme.replacement("../palette", "/project/src/palette.ts"),
+            Some("/project/src/palette.dark.ts")
+        );
+        assert_eq!(theme.replacement("./button", "/project/src/button.ts"), None);
+    }
+
+    #[test]
+    fn test_cache_key_and_scope() {
+        let theme = dark();
+        assert_eq!(Theme::cache_key(None, "/a.ts"), "/a.ts");
+        assert_eq!(Theme::cache_key(Some(&theme), "/a.ts"), "/a.ts?theme=dark");
+        assert_eq!(
+            theme.scope_css(".btn-abc {\ncolor: white;\n}", false),
+            ".theme-dark {\n.btn-abc {\ncolor: white;\n}\n}"
+        );
+    }
+}
@@ -1,4 +1,7 @@
+use super::class_name::ClassNameTemplate;
 use super::error::TransformError;
+use super::library_imports::{LibraryImport, parse_library_imports};
+use super::themes::{Theme, parse_themes};
 use crate::PREFIX;
 use js_sys::Array;
 use std::collections::HashMap;
@@ -7,32 +10,49 @@ use wasm_bindgen::prelude::*;
 #[wasm_bindgen]
 #[derive(Clone)]
 pub struct Transformer {
-    pub(crate) cwd: String,
+    /// generated names hash file paths relative to it, see `project_relative_path`
+    pub(crate) project_root: String,
     pub(crate) ignored_imports: HashMap<String, Vec<String>>,
+    /// module specifier -> exports providing `css`, `style`, `extraClass` or `styled`
+    pub(crate) library_imports: HashMap<String, LibraryImport>,
+    /// variants each styled file is evaluated again for, with some modules replaced
+    pub(crate) themes: Vec<Theme>,
 
     pub(crate) load_file: js_sys::Function,
     pub(crate) css_file_store_ref: String,
     pub(crate) value_cache_ref: String,
     pub(crate) require_ref: Option<String>,
     pub(crate) css_extension: String,
+    pub(crate) raw_extensions: Vec<String>,
     pub(crate) wrap_selectors_with_global: bool,
+    pub(crate) class_name_template: ClassNameTemplate,
 
     pub(crate) use_require: bool,
     pub(crate) debug: bool,
     pub(crate) atomic: bool,
+    /// compile `style` templates to React style objects in every file
+    pub(crate) style_objects: bool,
+    /// leave the CSS of `css` blocks which are never referenced out of the output
+    pub(crate) remove_unused_css: bool,
+    pub(crate) return_css: bool,
 }
 
 #[wasm_bindgen]
 impl Transformer {
     #[wasm_bindgen(constructor)]
     #[allow(clippy::new_without_default)]
-    pub fn new(opts: JsValue) -> Self {
+    pub fn new(opts: JsValue) -> Result<Self, JsValue> {
         let global = js_sys::global();
 
         let cwd = js_sys::Reflect::get(&opts, &JsValue::from_str("cwd"))
             .unwrap()
             .as_string()
             .unwrap();
+        // e.g. the workspace root, so packages of a monorepo hash the same from every app
+        let project_root = js_sys::Reflect::get(&opts, &JsValue::from_str("projectRoot"))
+            .ok()
+            .and_then(|v| v.as_string())
+            .unwrap_or(cwd);
 
         let ignored_imports = js_sys::Reflect::get(&opts, &JsValue::from_str("ignoredImports"))
             .ok()
@@ -55,6 +75,9 @@ impl Transformer {
             })
             .unwrap_or_default();
 
+        let library_imports = parse_library_imports(&opts);
+        let themes = parse_themes(&opts);
+
         let load_file = js_sys::Reflect::get(&opts, &JsValue::from_str("loadFile"))
             .unwrap()
             .dyn_into::<js_sys::Function>()
@@ -65,12 +88,39 @@ impl Transformer {
             .as_string()
             .unwrap();
 
+        let raw_extensions = js_sys::Reflect::get(&opts, &JsValue::from_str("rawExtensions"))
+            .ok()
+            .filter(js_sys::Array::is_array)
+            .map(|raw_extensions| {
+                js_sys::Array::from(&raw_extensions)
+                    .iter()
+                    .filter_map(|item| item.as_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+
         let wrap_selectors_with_global =
             js_sys::Reflect::get(&opts, &JsValue::from_str("wrapSelectorsWithGlobal"))
                 .unwrap()
                 .as_bool()
                 .unwrap_or(false);
 
+        let class_name_template =
+            match js_sys::Reflect::get(&opts, &JsValue::from_str("classNameTemplate"))
+                .ok()
+                .and_then(|v| v.as_string())
+            {
+                Some(template) => ClassNameTemplate::parse(&template)
+                    .map_err(|message| JsValue::from(js_sys::Error::new(&message)))?,
+                None => ClassNameTemplate::default(),
+            }
+            .with_hash_salt(
+                js_sys::Reflect::get(&opts, &JsValue::from_str("hashSalt"))
+                    .ok()
+                    .and_then(|v| v.as_string())
+                    .unwrap_or_default(),
+            );
+
         let random_suffix = crate::utils::generate_random_id(8);
 
         let css_cache = js_sys::Reflect::get(&opts, &JsValue::from_str("cssCache")).unwrap();
@@ -96,6 +146,21 @@ impl Transformer {
             .as_bool()
             .unwrap_or_default();
 
+        let style_objects = js_sys::Reflect::get(&opts, &JsValue::from_str("styleObjects"))
+            .unwrap()
+            .as_bool()
+            .unwrap_or_default();
+
+        let remove_unused_css = js_sys::Reflect::get(&opts, &JsValue::from_str("removeUnusedCss"))
+            .unwrap()
+            .as_bool()
+            .unwrap_or(true);
+
+        let return_css = js_sys::Reflect::get(&opts, &JsValue::from_str("returnCss"))
+            .unwrap()
+            .as_bool()
+            .unwrap_or_default();
+
         let create_require = js_sys::Reflect::get(&opts, &JsValue::from_str("createRequire"))
             .ok()
             .and_then(|v| v.dyn_into::<js_sys::Function>().ok());
@@ -108,21 +173,28 @@ impl Transformer {
             require_ref
         });
 
-        Self {
-            cwd,
+        Ok(Self {
+            project_root,
             ignored_imports,
+            library_imports,
+            themes,
 
             load_file,
             css_file_store_ref,
             value_cache_ref,
             require_ref,
             css_extension,
+            raw_extensions,
             wrap_selectors_with_global,
+            class_name_template,
 
             use_require,
             debug,
             atomic,
-        }
+            style_objects,
+            remove_unused_css,
+            return_css,
+        })
     }
 
     /// loads file contents and id
@@ -1,6 +1,6 @@
 use crate::utils::binding_pattern_kind_get_idents;
 use crate::PREFIX;
-use oxc_ast::ast::{Class, Function, VariableDeclarator};
+use oxc_ast::ast::{Class, Expression, Function, VariableDeclarator};
 use oxc_span::Span;
 use std::collections::HashSet;
 use wasm_bindgen::prelude::*;
@@ -33,6 +33,14 @@ impl Drop for ExportedJSValue {
     }
 }
 
+/// Arguments of the `extraClass(...)` calls in a template
+pub(crate) struct ExtraClasses<'alloc> {
+    /// string literals and arguments folded at compile time, split on whitespace
+    pub names: Vec<String>,
+    /// arguments only known once evaluated, e.g. imported values
+    pub expressions: Vec<Expression<'alloc>>,
+}
+
 pub(crate) enum VirtualProgramInsert<'alloc> {
     VariableDeclarator(VariableDeclarator<'alloc>),
     FunctionDeclaration(Function<'alloc>),
@@ -80,9 +88,25 @@ pub struct CssVariableIdentifier {
     /// The generated CSS class name (e.g., "myClass-abc123")
     pub class_name: String,
     /// Extra classes from extraClass() calls
+    #[allow(dead_code)]
     pub extra_classes: Vec<String>,
     /// Source span of the original css`...` block
     pub span: Span,
+    /// The CSS when the template could be folded at compile time
+    pub static_css: Option<String>,
+    /// Whether the template styles a class or defines an animation
+    pub kind: CssBlockKind,
+}
+
+/// What kind of CSS block a template is emitted as
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CssBlockKind {
+    /// `.class-name { ... }`
+    Class,
+    /// `@keyframes animation-name { ... }`
+    Keyframes,
+    /// unscoped CSS from `globalCss`, preceded by a `/* name */` marker for source maps
+    Global,
 }
 
 impl CssVariableIdentifier {
@@ -97,6 +121,40 @@ impl CssVariableIdentifier {
             class_name,
             extra_classes,
             span,
+            static_css: None,
+            kind: CssBlockKind::Class,
+        }
+    }
+
+    /// Whether the CSS is emitted without a class selector
+    pub fn is_global(&self) -> bool {
+        // `_Global` prefixed variable names are the deprecated way to write global styles
+        self.kind == CssBlockKind::Global || self.class_name.starts_with("_Global")
+    }
+
+    /// Wraps the template's CSS (or a JS expression producing it) into its block
+    pub fn css_block(&self, css: &str, wrap_selectors_with_global: bool) -> String {
+        match self.kind {
+            CssBlockKind::Global => format!("/* {} */\n{css}\n", self.class_name),
+            CssBlockKind::Keyframes if wrap_selectors_with_global => {
+                format!("@keyframes :global({}) {{\n{css}\n}}", self.class_name)
+            }
+            CssBlockKind::Keyframes => format!("@keyframes {} {{\n{css}\n}}", self.class_name),
+            CssBlockKind::Class if self.class_name.starts_with("_Global") => format!("{css}\n"),
+            CssBlockKind::Class if wrap_selectors_with_global => {
+                format!(":global(.{}) {{\n{css}\n}}", self.class_name)
+            }
+            CssBlockKind::Class => format!(".{} {{\n{css}\n}}", self.class_name),
+        }
+    }
+
+    /// The start of the block in the generated CSS, used for source maps
+    pub fn selector(&self) -> Option<String> {
+        match self.kind {
+            CssBlockKind::Global => Some(format!("/* {} */", self.class_name)),
+            CssBlockKind::Keyframes => Some(format!("@keyframes {}", self.class_name)),
+            CssBlockKind::Class if self.class_name.starts_with("_Global") => None,
+            CssBlockKind::Class => Some(format!(".{}", self.class_name)),
         }
     }
 }
@@ -107,3 +165,52 @@ pub enum EvaluateProgramReturnStatus {
     Transfomred,
     NotTransformed,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identifier(class_name: &str, kind: CssBlockKind) -> CssVariableIdentifier {
+        let mut css_var = CssVariableIdentifier::new(
+            "fadeIn".to_string(),
+            class_name.to_string(),
+            vec![],
+            Span::default(),
+        );
+        css_var.kind = kind;
+        css_var
+    }
+
+    #[test]
+    fn test_css_block() {
+        let class = identifier("button-abc123", CssBlockKind::Class);
+        assert_eq!(class.css_block("color: red;", false), ".button-abc123 {\ncolor: red;\n}");
+        assert_eq!(class.selector().as_deref(), Some(".button-abc123"));
+
+        let keyframes = identifier("fadeIn-abc123", CssBlockKind::Keyframes);
+        assert_eq!(
+            keyframes.css_block("to { opacity: 1; }", false),
+            "@keyframes fadeIn-abc123 {\nto { opacity: 1; }\n}"
+        );
+        assert_eq!(
+            keyframes.css_block("to { opacity: 1; }", true),
+            "@keyframes :global(fadeIn-abc123) {\nto { opacity: 1; }\n}"
+        );
+        assert_eq!(keyframes.selector().as_deref(), Some("@keyframes fadeIn-abc123"));
+
+        let global = identifier("reset-abc123", CssBlockKind::Global);
+        assert_eq!(
+            global.css_block("body { margin: 0; }", true),
+            "/* reset-abc123 */\nbody { margin: 0; }\n"
+        );
+        assert_eq!(global.selector().as_deref(), Some("/* reset-abc123 */"));
+
+        let deprecated_global = identifier("_GlobalReset", CssBlockKind::Class);
+        assert!(deprecated_global.is_global());
+        assert_eq!(
+            deprecated_global.css_block("body { margin: 0; }", true),
+            "body { margin: 0; }\n"
+        );
+        assert_eq!(deprecated_global.selector(), None);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::error_mapping::get_pos_from_offset;
+use crate::utils;
+use oxc_ast::AstKind;
+use oxc_ast::ast::{BindingPatternKind, Declaration, Expression, Program, Statement};
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{GetSpan, Span};
+use std::collections::HashSet;
+use wasm_bindgen::prelude::*;
+
+/// A `css` block assigned to a variable which is never read
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct UnusedCssBlock {
+    pub name: String,
+    /// span of the css template or `css({...})` call
+    pub span: Span,
+}
+
+/// finds css blocks whose binding has no read references, exported bindings count as used
+pub(crate) fn find_unused_css_blocks(
+    program: &Program,
+    css_function_names: &HashSet<String>,
+) -> Vec<UnusedCssBlock> {
+    let exported = exported_declarator_spans(program);
+    let semantic = SemanticBuilder::new().build(program).semantic;
+    let scoping = semantic.scoping();
+
+    semantic
+        .nodes()
+        .iter()
+        .filter_map(|node| {
+            let AstKind::VariableDeclarator(declarator) = node.kind() else {
+                return None;
+            };
+            let BindingPatternKind::BindingIdentifier(binding) = &declarator.id.kind else {
+                return None;
+            };
+            let init = declarator.init.as_ref()?.without_parentheses();
+
+            let tag = match init {
+                Expression::TaggedTemplateExpression(template) => &template.tag,
+                Expression::CallExpression(call) => &call.callee,
+                _ => return None,
+            };
+            if !utils::expression_path(tag).is_some_and(|path| css_function_names.contains(&path))
+                || exported.contains(&declarator.span)
+                || scoping
+                    .get_resolved_references(binding.symbol_id())
+                    .any(|reference| reference.is_read())
+            {
+                return None;
+            }
+
+            Some(UnusedCssBlock {
+                name: binding.name.to_string(),
+                span: init.span(),
+            })
+        })
+        .collect()
+}
+
+/// `export const button = css...` is used by importers even without local references
+fn exported_declarator_spans(program: &Program) -> HashSet<Span> {
+    program
+        .body
+        .iter()
+        .filter_map(|statement| match statement {
+            Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                Some(Declaration::VariableDeclaration(declaration)) => Some(declaration),
+                _ => None,
+            },
+            _ => None,
+        })
+        .flat_map(|declaration| declaration.declarations.iter().map(|it| it.span))
+        .collect()
+}
+
+/// the `warnings` of a transform result, `[{ message, filepath, line, column }]`
+pub(crate) fn unused_css_warnings(
+    blocks: &[UnusedCssBlock],
+    filepath: &str,
+    code: &str,
+) -> js_sys::Array {
+    blocks
+        .iter()
+        .map(|block| {
+            let (line, column) = get_pos_from_offset(code, block.span.start as usize);
+            let warning = js_sys::Object::new();
+            let set = |key: &str, value: JsValue| {
+                js_sys::Reflect::set(&warning, &JsValue::from_str(key), &value).unwrap();
+            };
+            set(
+                "message",
+                JsValue::from_str(&format!("css block '{}' is never used", block.name)),
+            );
+            set("filepath", JsValue::from_str(filepath));
+            set("line", JsValue::from_f64(line as f64));
+            set("column", JsValue::from_f64(column as f64));
+            JsValue::from(warning)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_unused_css_blocks() {
+        let allocator = oxc_allocator::Allocator::default();
+        let code = r#"
+            import { css } from "@style-this/core";
+            const used = css`color: red;`;
+            const unused = css`color: blue;`;
+            const unusedObject = css({ color: "green" });
+            const composed = css`${used}`;
+            export const exported = css``;
+            const reexported = css``;
+            export { reexported };
+            function Card() {
+                const local = css``;
+                return <div className={composed} />;
+            }
+        "#;
+        let program = oxc_parser::Parser::new(&allocator, code, oxc_span::SourceType::tsx())
+            .parse()
+            .program;
+
+        let names = find_unused_css_blocks(&program, &HashSet::from(["css".to_string()]))
+            .into_iter()
+            .map(|block| block.name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["unused", "unusedObject", "local"]);
+    }
+}
@@ -1,64 +1,109 @@
-use super::cache::CSS_CLASSNAME_CACHE;
+use super::cache::{CLASS_NAME_OWNERS, CSS_CLASSNAME_CACHE};
+use super::class_name::{
+    BindingScopes, ClassNameParts, ClassNameTemplate, project_relative_path,
+};
+use super::compose::compose_function_name;
+use super::cx::compile_cx_calls;
+use super::dynamic_style::{DynamicStyles, attach_dynamic_styles};
 use super::error::TransformError;
-use super::types::{CssVariableIdentifier, VirtualProgramInsert};
+use super::object_css::object_to_css_function_name;
+use super::scope_analysis::{Reference, ScopeAnalysis};
+use super::static_eval::{fold_expression, StaticValue};
+use super::style_object::{StylePart, build_style_object, parse_style_template};
+use super::types::{CssBlockKind, CssVariableIdentifier, ExtraClasses, VirtualProgramInsert};
 use crate::ast;
 use crate::error_mapping::get_pos_from_offset;
 use crate::utils::{
     self, binding_pattern_kind_get_idents, replace_in_class_body_using_spans,
-    replace_in_expression_using_identifiers, replace_in_expression_using_spans,
-    replace_in_statement_using_spans,
+    replace_in_expression_using_spans, replace_in_statement_using_spans,
 };
 use crate::PREFIX;
 use oxc_allocator::{Allocator, CloneIn};
 use oxc_ast::ast::{
-    BindingPatternKind, Declaration, ExportDefaultDeclaration, ExportDefaultDeclarationKind,
-    Expression, ImportDeclarationSpecifier, Program, Statement,
-    TaggedTemplateExpression, VariableDeclarationKind, VariableDeclarator,
+    BindingIdentifier, BindingPatternKind, Declaration, ExportDefaultDeclaration,
+    ExportDefaultDeclarationKind, Expression, ExpressionStatement, ImportDeclarationSpecifier,
+    Program, Statement, TaggedTemplateExpression, VariableDeclarationKind, VariableDeclarator,
 };
 use oxc_ast::AstBuilder;
 use oxc_ast_visit::VisitMut;
-use oxc_semantic::ScopeFlags;
+use oxc_semantic::{ScopeFlags, SymbolId};
 use oxc_span::{GetSpan, Span};
 use std::collections::{HashMap, HashSet};
 
+/// name the default export is referenced by
+const DEFAULT_EXPORT_SENTINEL: &str = "__global__export__";
+
 pub struct VisitorTransformer<'a, 'alloc> {
     ast_builder: &'a AstBuilder<'alloc>,
     allocator: &'alloc Allocator,
     entrypoint: bool,
-    cwd: &'a str,
+    project_root: &'a str,
     program_filepath: &'a str,
     program_code: &'a str,
     value_cache: &'a mut HashSet<String>,
-
-    style_function_name: Option<String>,
-    css_function_name: Option<String>,
-    extra_class_function_name: Option<String>,
+    static_consts: HashMap<String, StaticValue>,
+
+    style_function_names: HashSet<String>,
+    css_function_names: HashSet<String>,
+    extra_class_function_names: HashSet<String>,
+    keyframes_function_names: HashSet<String>,
+    global_css_function_names: HashSet<String>,
+    create_tokens_function_names: HashSet<String>,
+    compose_function_names: HashSet<String>,
+    cx_function_names: HashSet<String>,
+    /// span of each `cx(...)` call -> identifiers in its arguments referring to top-level bindings
+    cx_calls: HashMap<Span, HashSet<String>>,
+    /// class lists of top-level `const` css bindings, to fold `cx(...)` arguments
+    class_lists: HashMap<String, String>,
+    /// placeholders of class lists in the output -> expression of the virtual program
+    /// evaluating to their value, see `output_class_list`
+    evaluated_class_lists: HashMap<String, String>,
+    /// top-level css bindings in atomic mode -> their variable in the virtual program
+    atomic_blocks: HashMap<String, String>,
+    /// custom properties of css templates using props or state, set by the JSX using them
+    dynamic_styles: DynamicStyles<'alloc>,
+    class_name_template: &'a ClassNameTemplate,
+    /// class names generated for this file, to keep them unique within it
+    class_names: HashSet<String>,
+    binding_scopes: BindingScopes,
+    scopes: ScopeAnalysis,
+    /// how often a block key was used, see `block_key`
+    block_keys: HashMap<String, u32>,
 
     store: String,
-    referenced_idents: Vec<HashSet<String>>,
+    /// top-level bindings and globals the virtual program needs
+    referenced_idents: HashSet<String>,
+    /// local bindings the virtual program needs
+    referenced_symbols: HashSet<SymbolId>,
     css_variable_identifiers: Vec<CssVariableIdentifier>,
     style_variable_identifiers: HashSet<String>,
     exported_idents: HashSet<String>,
     scope_depth: u32,
 
-    scan_pass: bool,
-    aliases: Vec<HashMap<String, Option<String>>>,
-    dynamic_variable_names: Vec<HashSet<String>>,
     namespace_imports: HashMap<String, (String, HashSet<String>)>,
     unique_number_counter: u32,
-    css_unique_number_counter: u32,
 
     replacement_points: HashMap<Span, Expression<'alloc>>,
 
     random: utils::SeededRandom,
     tmp_program: Program<'alloc>,
     tmp_program_statement_buffer: Vec<Vec<Statement<'alloc>>>,
+    /// function declarations of each statement list being visited with their position in the
+    /// statement buffer, they are hoisted so whether they're referenced is known at its end
+    function_declarations: Vec<Vec<(usize, oxc_ast::ast::Function<'alloc>)>>,
 
     atomic: bool,
+    /// compile `style` templates to React style objects instead of removing them
+    style_objects: bool,
 
     pub error: Option<TransformError>,
 }
 
+/// whether the callee is one of the names a library function is imported as
+fn is_call_to(names: &HashSet<String>, callee: &Expression) -> bool {
+    utils::expression_path(callee).is_some_and(|path| names.contains(&path))
+}
+
 impl<'a, 'alloc> VisitorTransformer<'a, 'alloc> {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -67,30 +112,55 @@ impl<'a, 'alloc> VisitorTransformer<'a, 'alloc> {
         entrypoint: bool,
         store: &str,
         referenced_idents: HashSet<String>,
-        cwd: &'a str,
+        project_root: &'a str,
         program_filepath: &'a str,
         program_code: &'a str,
         value_cache: &'a mut HashSet<String>,
-        css_function_name: Option<String>,
-        style_function_name: Option<String>,
-        extra_class_function_name: Option<String>,
+        static_consts: HashMap<String, StaticValue>,
+        css_function_names: HashSet<String>,
+        style_function_names: HashSet<String>,
+        extra_class_function_names: HashSet<String>,
+        keyframes_function_names: HashSet<String>,
+        global_css_function_names: HashSet<String>,
+        create_tokens_function_names: HashSet<String>,
+        compose_function_names: HashSet<String>,
+        cx_function_names: HashSet<String>,
+        class_name_template: &'a ClassNameTemplate,
         atomic: bool,
+        style_objects: bool,
     ) -> Self {
         Self {
             ast_builder,
             allocator,
             entrypoint,
-            cwd,
+            project_root,
             program_filepath,
             program_code,
             value_cache,
-
-            css_function_name,
-            style_function_name,
-            extra_class_function_name,
+            static_consts,
+
+            css_function_names,
+            style_function_names,
+            extra_class_function_names,
+            keyframes_function_names,
+            global_css_function_names,
+            create_tokens_function_names,
+            compose_function_names,
+            cx_function_names,
+            cx_calls: Default::default(),
+            class_lists: Default::default(),
+            evaluated_class_lists: Default::default(),
+            atomic_blocks: Default::default(),
+            dynamic_styles: Default::default(),
+            class_name_template,
+            class_names: Default::default(),
+            binding_scopes: Default::default(),
+            scopes: Default::default(),
+            block_keys: Default::default(),
 
             store: store.to_string(),
-            referenced_idents: vec![referenced_idents],
+            referenced_idents,
+            referenced_symbols: Default::default(),
             css_variable_identifiers: Default::default(),
             style_variable_identifiers: Default::default(),
             exported_idents: Default::default(),
@@ -98,18 +168,16 @@ impl<'a, 'alloc> VisitorTransformer<'a, 'alloc> {
 
             replacement_points: Default::default(),
 
-            scan_pass: false,
-            aliases: Default::default(),
-            dynamic_variable_names: Default::default(),
             namespace_imports: Default::default(),
             unique_number_counter: 0,
-            css_unique_number_counter: 0,
 
             random: Default::default(),
             tmp_program: utils::build_new_ast(allocator).program,
             tmp_program_statement_buffer: Default::default(),
+            function_declarations: Default::default(),
 
             atomic,
+            style_objects,
 
             error: None,
         }
@@ -124,6 +192,7 @@ impl<'a, 'alloc> VisitorTransformer<'a, 'alloc> {
         HashMap<String, HashSet<String>>,
         HashSet<String>,
         Program<'alloc>,
+        HashMap<String, String>,
     ) {
         let namespace_imports_by_module: HashMap<String, HashSet<String>> = self
             .namespace_imports
@@ -136,10 +205,11 @@ impl<'a, 'alloc> VisitorTransformer<'a, 'alloc> {
 
         (
             self.css_variable_identifiers,
-            self.referenced_idents.into_iter().next().unwrap(),
+            self.referenced_idents,
             namespace_imports_by_module,
             self.exported_idents,
             self.tmp_program,
+            self.evaluated_class_lists,
         )
     }
 }
@@ -154,26 +224,32 @@ impl<'a, 'alloc> VisitorTransformer<'a, 'alloc> {
 
         oxc_ast_visit::walk_mut::walk_function(self, it, flags);
 
-        let Some(id) = &it.id else { return };
-        let name = id.name.as_str();
+        if it.id.is_none() {
+            return;
+        }
+        let function_declaration = it.clone_in(self.allocator);
+        match self.function_declarations.last_mut() {
+            Some(function_declarations) => function_declarations.push((pos, function_declaration)),
+            None => self.insert_function_declaration(function_declaration, pos),
+        }
+    }
 
-        if !self.is_variable_referenced(name) {
+    fn insert_function_declaration(
+        &mut self,
+        mut function_declaration: oxc_ast::ast::Function<'alloc>,
+        pos: usize,
+    ) {
+        let Some(id) = &function_declaration.id else { return };
+        if !self.is_binding_referenced(id) {
             return;
         }
 
-        let mut function_declaration = it.clone_in(self.allocator);
+        let references = self.scopes.function_references(&function_declaration);
+        if self.check_dynamic_variable_access(&references, function_declaration.span.start) {
+            return;
+        }
 
         if let Some(body) = &mut function_declaration.body {
-            let right_references = body
-                .statements
-                .iter()
-                .flat_map(|stmt| utils::statement_get_references(stmt))
-                .collect::<Vec<_>>();
-
-            if self.check_dynamic_variable_access(&right_references, body.span().start) {
-                return;
-            }
-
             // transform
             for statement in &mut body.statements {
                 replace_in_statement_using_spans(
@@ -183,6 +259,9 @@ impl<'a, 'alloc> VisitorTransformer<'a, 'alloc> {
                 );
             }
         }
+        self.scopes
+            .alias_replacer(self.ast_builder)
+            .visit_function(&mut function_declaration, ScopeFlags::Function);
 
         self.insert_into_virtual_program(
             VirtualProgramInsert::FunctionDeclaration(function_declaration),
@@ -196,9 +275,12 @@ impl<'a, 'alloc> VisitorTransformer<'a, 'alloc> {
         oxc_ast_visit::walk_mut::walk_class(self, it);
 
         let Some(id) = &it.id else { return };
-        let name = id.name.as_str();
+        if !self.is_binding_referenced(id) {
+            return;
+        }
 
-        if !self.is_variable_referenced(name) {
+        let references = self.scopes.class_references(it);
+        if self.check_dynamic_variable_access(&references, it.span.start) {
             return;
         }
 
@@ -208,44 +290,62 @@ impl<'a, 'alloc> VisitorTransformer<'a, 'alloc> {
             &mut self.replacement_points,
         );
 
+        let mut class_declaration = it.clone_in(self.allocator);
+        self.scopes
+            .alias_replacer(self.ast_builder)
+            .visit_class(&mut class_declaration);
+
         self.insert_into_virtual_program(
-            VirtualProgramInsert::ClassDeclaration(it.clone_in(self.allocator)),
+            VirtualProgramInsert::ClassDeclaration(class_declaration),
             Some(pos),
         );
     }
 
     /// creates a class name or gets it from cache
-    fn create_virtual_css_template(&mut self, variable_name: &str) -> String {
-        let unique_number = self.css_unique_number();
-        
+    fn create_virtual_css_template(&mut self, variable_name: &str, span: Span) -> String {
+        let block_key = self.block_key(variable_name, span);
+        // For _Global prefixed variables, use the variable name as-is without random suffix
+        // (deprecated in favor of `globalCss`)
+        let is_global = variable_name.starts_with("_Global");
+
+        let (line, _) = get_pos_from_offset(self.program_code, span.start as usize);
+        // blocks without a variable are named after what contains them
+        let display_name = variable_name
+            .starts_with(&format!("{PREFIX}_expression_"))
+            .then(|| self.binding_scopes.display_name(span))
+            .flatten();
+        let parts = ClassNameParts {
+            name: display_name.as_deref().unwrap_or(variable_name),
+            filepath: &project_relative_path(self.project_root, self.program_filepath),
+            line,
+            block_key: &block_key,
+        };
+        // a block moved to another line or renamed through its container renders differently
+        let cache_key = parts.cache_key();
+
         // Try to get from cache if entrypoint
-        if self.entrypoint {
-            if let Some(cached) = CSS_CLASSNAME_CACHE.with(|cache| {
+        if self.entrypoint
+            && let Some(cached) = CSS_CLASSNAME_CACHE.with(|cache| {
                 cache
                     .borrow()
                     .get(self.program_filepath)
-                    .and_then(|file_cache| file_cache.get(&unique_number))
+                    .and_then(|file_cache| file_cache.get(&cache_key))
                     .cloned()
-            }) {
-                return cached;
+            })
+        {
+            if !is_global {
+                self.claim_class_name(&cached, span);
             }
+            return cached;
         }
 
         // Generate new class name
-        // For _Global prefixed variables, use the variable name as-is without random suffix
-        let class_name = if variable_name.starts_with("_Global") {
+        let class_name = if is_global {
             variable_name.to_string()
         } else {
-            let relative_program_filepath = self
-                .program_filepath
-                .strip_prefix(self.cwd)
-                .unwrap_or(self.program_filepath);
-
-            let random_suffix = self
-                .random
-                .random_string(6, &format!("{relative_program_filepath}_{unique_number}"));
-
-            format!("{variable_name}-{random_suffix}")
+            let class_name = self.class_name_template.render(&parts, &mut self.random);
+            self.claim_class_name(&class_name, span);
+            class_name
         };
 
         // Cache it
@@ -254,54 +354,146 @@ impl<'a, 'alloc> VisitorTransformer<'a, 'alloc> {
                 .borrow_mut()
                 .entry(self.program_filepath.to_string())
                 .or_default()
-                .insert(unique_number, class_name.clone());
+                .insert(cache_key, class_name.clone());
         });
 
         class_name
     }
 
-    fn get_alias(&self, name: &str) -> Option<&str> {
-        for alias_map in self.aliases.iter().rev() {
-            if let Some(alias) = alias_map.get(name) {
-                return alias.as_ref().map(|x| x.as_str());
-            }
+    /// identifies a block by the declarations it's in (`Card.button`), adding a hash of its
+    /// source for blocks without a variable of their own, so class names only change when
+    /// the block itself is renamed or edited
+    fn block_key(&mut self, variable_name: &str, span: Span) -> String {
+        let mut key = self.binding_scopes.path(span);
+        if variable_name.starts_with(&format!("{PREFIX}_expression_")) {
+            let source = self
+                .program_code
+                .get(span.start as usize..span.end as usize)
+                .unwrap_or_default();
+            key.push('#');
+            key.push_str(&self.random.random_string(8, source));
+        }
+
+        // blocks with the same name or content in the same declaration are numbered
+        let count = self.block_keys.entry(key.clone()).or_default();
+        *count += 1;
+        if *count > 1 {
+            key.push_str(&format!("~{count}"));
         }
-        None
+        key
     }
 
-    fn reference_variable(&mut self, name: String) {
-        for (depth, alias_map) in self.aliases.iter().enumerate().rev() {
-            if alias_map.contains_key(&name) {
-                self.referenced_idents.get_mut(depth).unwrap().insert(name);
-                return;
+    /// registers a generated name for this file, a name the template produced for another
+    /// block of this file or another file is an error, since renaming one of them would depend
+    /// on which file is transformed first
+    fn claim_class_name(&mut self, class_name: &str, span: Span) {
+        let filepath = self.program_filepath;
+        let owner = CLASS_NAME_OWNERS.with(|owners| {
+            owners
+                .borrow_mut()
+                .entry(class_name.to_string())
+                .or_insert_with(|| filepath.to_string())
+                .clone()
+        });
+
+        if owner != filepath || !self.class_names.insert(class_name.to_string()) {
+            let (row, column) = get_pos_from_offset(self.program_code, span.start as usize);
+            self.error = Some(TransformError::ClassNameCollision {
+                class_name: class_name.to_string(),
+                filepath: filepath.to_string(),
+                other_filepath: owner,
+                row,
+                column,
+            });
+        }
+    }
+
+    /// marks a binding as needed by the virtual program, along with what its declaration reads
+    fn reference_variable(&mut self, reference: &Reference) {
+        let newly_referenced = match reference.symbol {
+            Some(symbol) if self.scopes.is_local_symbol(symbol) => {
+                self.referenced_symbols.insert(symbol)
             }
+            // globals and bindings the visitor created are referenced by name
+            _ => self.referenced_idents.insert(reference.name.clone()),
+        };
+
+        if newly_referenced && let Some(symbol) = reference.symbol {
+            self.reference_dependencies(symbol);
+        }
+    }
+
+    /// declarations can come after what references them (hoisted functions), so everything
+    /// they read is referenced up front
+    fn reference_dependencies(&mut self, symbol: SymbolId) {
+        if self.scopes.is_dynamic_symbol(symbol) {
+            return;
         }
+        for dependency in self.scopes.dependencies(symbol).to_vec() {
+            self.reference_variable(&dependency);
+        }
+    }
 
-        // if there's no known variable, use the global scope as fallback
-        self.referenced_idents.first_mut().unwrap().insert(name);
+    fn is_binding_referenced(&self, ident: &BindingIdentifier) -> bool {
+        let reference = self.scopes.binding_reference(ident);
+        match reference.symbol {
+            Some(symbol) => self.is_symbol_referenced(symbol),
+            None => self.referenced_idents.contains(&reference.name),
+        }
     }
 
-    fn is_variable_referenced(&self, name: &str) -> bool {
-        self.referenced_idents.iter().any(|set| set.contains(name))
+    fn is_symbol_referenced(&self, symbol: SymbolId) -> bool {
+        if self.scopes.is_local_symbol(symbol) {
+            self.referenced_symbols.contains(&symbol)
+        } else {
+            self.referenced_idents.contains(self.scopes.symbol_name(symbol))
+        }
     }
 
-    /// checks if the variable exits in the current scope or any scope above it
-    fn variable_exists(&self, name: &str) -> bool {
-        self.aliases.iter().any(|aliases| aliases.contains_key(name))
+    /// the name of a binding in the virtual program
+    fn resolve_binding_name(&self, ident: &BindingIdentifier) -> String {
+        self.scopes
+            .binding_reference(ident)
+            .symbol
+            .and_then(|symbol| self.scopes.alias(symbol))
+            .unwrap_or_else(|| ident.name.to_string())
     }
 
-    fn get_dynamic_variable(&self, name: &str) -> bool {
-        self.dynamic_variable_names.iter().rev().any(|vars| vars.contains(name))
+    fn is_dynamic_expression(&self, expression: &Expression<'alloc>) -> bool {
+        self.scopes
+            .expression_references(expression)
+            .iter()
+            .any(|reference| self.scopes.is_dynamic(reference))
     }
 
     /// Checks if any references are dynamic variables and sets error if found
     /// Returns true if error was set (meaning there was a dynamic variable access)
-    fn check_dynamic_variable_access(&mut self, references: &[String], span_start: u32) -> bool {
-        for ident in references {
-            if self.get_dynamic_variable(ident) {
+    fn check_dynamic_variable_access(&mut self, references: &[Reference], span_start: u32) -> bool {
+        for reference in references {
+            if self.scopes.is_dynamic(reference) {
                 let (row, column) = get_pos_from_offset(self.program_code, span_start as usize);
                 self.error = Some(TransformError::AccessDynamicVariableError {
-                    variable: ident.to_string(),
+                    variable: reference.name.clone(),
+                    filepath: self.program_filepath.to_string(),
+                    row,
+                    column,
+                });
+                return true;
+            }
+        }
+        false
+    }
+
+    /// css only sees the value a binding has when the template is evaluated, which the virtual
+    /// program can't reproduce when it's reassigned conditionally or in a function
+    fn check_mutated_variable_access(&mut self, references: &[Reference]) -> bool {
+        for reference in references {
+            if let Some(symbol) = reference.symbol
+                && let Some(span) = self.scopes.indeterminate_write(symbol)
+            {
+                let (row, column) = get_pos_from_offset(self.program_code, span.start as usize);
+                self.error = Some(TransformError::MutatedVariableError {
+                    variable: reference.name.clone(),
                     filepath: self.program_filepath.to_string(),
                     row,
                     column,
@@ -327,7 +519,7 @@ impl<'a, 'alloc> VisitorTransformer<'a, 'alloc> {
                 let variable_declaration = ast::build_variable_declaration_ident(
                     self.ast_builder,
                     span,
-                    self.get_alias(variable_name).unwrap_or(variable_name),
+                    variable_name,
                     &format!("{}['{variable_name}']", self.store),
                 );
 
@@ -437,6 +629,7 @@ impl<'a, 'alloc> VisitorTransformer<'a, 'alloc> {
         variable_name: &str,
         class_name: &str,
         extra_classes: Vec<String>,
+        kind: CssBlockKind,
     ) {
         let span = it.span;
 
@@ -444,7 +637,6 @@ impl<'a, 'alloc> VisitorTransformer<'a, 'alloc> {
         let mut new_quasis = oxc_allocator::Vec::new_in(self.allocator);
         let mut new_expressions = oxc_allocator::Vec::new_in(self.allocator);
         
-        let extra_class_name = self.extra_class_function_name.as_deref();
         let mut quasi_iter = it.quasi.quasis.iter();
         
         // First quasi always exists
@@ -454,19 +646,8 @@ impl<'a, 'alloc> VisitorTransformer<'a, 'alloc> {
             let mut current_tail = first_quasi.tail;
             
             for expr in it.quasi.expressions.iter() {
-                let is_extra_class = if let Some(name) = extra_class_name {
-                    if let Expression::CallExpression(call) = expr {
-                        if let Expression::Identifier(ident) = &call.callee {
-                            ident.name.as_str() == name
-                        } else {
-                            false
-                        }
-                    } else {
-                        false
-                    }
-                } else {
-                    false
-                };
+                let is_extra_class = matches!(expr, Expression::CallExpression(call)
+                    if is_call_to(&self.extra_class_function_names, &call.callee));
                 
                 let next_quasi = quasi_iter.next().unwrap();
                 
@@ -487,7 +668,9 @@ impl<'a, 'alloc> VisitorTransformer<'a, 'alloc> {
                         value,
                         lone_surrogates: false,
                     });
-                    new_expressions.push(expr.clone_in(self.allocator));
+                    let mut expr = expr.clone_in(self.allocator);
+                    self.rewrite_compose_call(&mut expr);
+                    new_expressions.push(expr);
                     current_quasi_raw = next_quasi.value.raw.as_str();
                     current_span = next_quasi.span;
                     current_tail = next_quasi.tail;
@@ -509,23 +692,28 @@ impl<'a, 'alloc> VisitorTransformer<'a, 'alloc> {
 
         utils::trim_newlines(self.ast_builder, &mut new_quasis);
 
+        let static_css = self.fold_css_template(&new_quasis, &new_expressions);
+
         let mut right = self.ast_builder.expression_template_literal(
             span,
             new_quasis,
             new_expressions,
         );
 
-        replace_in_expression_using_identifiers(self.ast_builder, &mut right, &|name| {
-            self.get_alias(name).map(|v| v.to_string())
-        });
+        self.scopes
+            .alias_replacer(self.ast_builder)
+            .visit_expression(&mut right);
 
         // Store the CSS variable identifier for the evaluator
-        self.css_variable_identifiers.push(CssVariableIdentifier::new(
+        let mut css_variable_identifier = CssVariableIdentifier::new(
             variable_name.to_string(),
             class_name.to_string(),
             extra_classes,
             span,
-        ));
+        );
+        css_variable_identifier.static_css = static_css;
+        css_variable_identifier.kind = kind;
+        self.css_variable_identifiers.push(css_variable_identifier);
 
         let stmt = Statement::ExpressionStatement(self.ast_builder.alloc_expression_statement(
             span,
@@ -544,513 +732,1052 @@ impl<'a, 'alloc> VisitorTransformer<'a, 'alloc> {
             .push(stmt);
     }
 
-    /// extracts extraClass("a b c") calls from template expressions
-    fn extract_extra_classes(&self, it: &TaggedTemplateExpression<'alloc>) -> Vec<String> {
-        let mut extra_classes = Vec::new();
-        
-        if let Some(extra_class_name) = &self.extra_class_function_name {
-            for expr in &it.quasi.expressions {
-                if let Expression::CallExpression(call) = expr {
-                    if let Expression::Identifier(ident) = &call.callee {
-                        if ident.name.as_str() == extra_class_name {
-                            // Extract string arguments
-                            for arg in &call.arguments {
-                                if let oxc_ast::ast::Argument::StringLiteral(str_lit) = arg {
-                                    // Split by whitespace and collect class names
-                                    for class in str_lit.value.split_whitespace() {
-                                        extra_classes.push(class.to_string());
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+    /// `compose(base)` inside a template includes the declarations of `base`, which the
+    /// evaluated program reads from the `css` property of the block
+    fn rewrite_compose_call(&self, expression: &mut Expression<'alloc>) {
+        if let Expression::CallExpression(call) = expression
+            && is_call_to(&self.compose_function_names, &call.callee)
+        {
+            call.callee =
+                ast::build_identifier(self.ast_builder, call.callee.span(), &compose_function_name());
         }
-        
-        extra_classes
     }
 
+    /// compiles a `css`, `style`, `keyframes` or `globalCss` template and returns the expression
+    /// replacing it, `None` if it can't be compiled. `binding` is the variable the template
+    /// initializes, anonymous templates get a generated one.
+    fn compile_tagged_template(
+        &mut self,
+        template: &mut TaggedTemplateExpression<'alloc>,
+        tag: &str,
+        binding: Option<&BindingIdentifier<'alloc>>,
+        top_level_const: bool,
+    ) -> Option<Expression<'alloc>> {
+        oxc_ast_visit::walk_mut::walk_tagged_template_expression(self, template);
+
+        let span = template.span;
+        let variable_name = &match binding {
+            Some(binding) => binding.name.to_string(),
+            None => format!("{PREFIX}_expression_{}", self.unique_number()),
+        };
 
-    fn alias_binding_pattern(&self, pattern: &mut BindingPatternKind<'alloc>) {
-        match pattern {
-            BindingPatternKind::BindingIdentifier(it) => {
-                if let Some(name) = self.get_alias(&it.name) {
-                    it.name = self.ast_builder.atom(name);
-                }
-            }
-            BindingPatternKind::ObjectPattern(pattern) => {
-                pattern
-                    .properties
-                    .iter_mut()
-                    .for_each(|v| self.alias_binding_pattern(&mut v.value.kind));
-
-                if let Some(rest) = &mut pattern.rest {
-                    self.alias_binding_pattern(&mut rest.argument.kind);
-                }
-            }
-            BindingPatternKind::ArrayPattern(pattern) => {
-                pattern
-                    .elements
-                    .iter_mut()
-                    .filter_map(|element| element.as_mut())
-                    .for_each(|element| self.alias_binding_pattern(&mut element.kind));
-
-                if let Some(rest) = &mut pattern.rest {
-                    self.alias_binding_pattern(&mut rest.argument.kind);
-                }
-            }
-            BindingPatternKind::AssignmentPattern(pattern) => {
-                self.alias_binding_pattern(&mut pattern.left.kind);
+        // animation names and global style markers are generated the same way as class names
+        let class_name = (!self.style_function_names.contains(tag))
+            .then(|| self.create_virtual_css_template(variable_name, span));
+        // props and local state used by css templates are passed as custom properties
+        let dynamic_values = match &class_name {
+            Some(class_name) if self.css_function_names.contains(tag) => {
+                self.extract_dynamic_interpolations(template, class_name)
             }
+            _ => vec![],
         };
-    }
-
-    fn unique_number(&mut self) -> u32 {
-        self.unique_number_counter += 1;
-        self.unique_number_counter
-    }
-
-    fn css_unique_number(&mut self) -> u32 {
-        self.css_unique_number_counter += 1;
-        self.css_unique_number_counter
-    }
-}
-
-impl<'a, 'alloc> VisitMut<'alloc> for VisitorTransformer<'a, 'alloc> {
-    // do a forward scan for variable declarations, then move backwards through statements
-    fn visit_statements(&mut self, it: &mut oxc_allocator::Vec<'alloc, Statement<'alloc>>) {
-        if self.error.is_some() {
-            return;
-        }
-
-        if self.scan_pass {
-            return;
-        }
-
-        let scan_pass = self.scan_pass;
-        self.scan_pass = true;
-        for el in it.iter_mut().rev() {
-            self.visit_statement(el);
-        }
-        self.scan_pass = false;
-        for el in it.iter_mut().rev() {
-            self.visit_statement(el);
-        }
-        self.scan_pass = scan_pass;
-    }
-
-    fn enter_scope(
-        &mut self,
-        _flags: oxc_semantic::ScopeFlags,
-        _scope_id: &std::cell::Cell<Option<oxc_semantic::ScopeId>>,
-    ) {
-        self.scope_depth += 1;
-        self.aliases.push(Default::default());
-        self.dynamic_variable_names.push(Default::default());
-        self.tmp_program_statement_buffer.push(Default::default());
-        if self.scope_depth != 1 {
-            self.referenced_idents.push(Default::default());
-        }
-    }
-
-    fn leave_scope(&mut self) {
-        self.aliases.pop();
-        self.dynamic_variable_names.pop();
 
-        let statements = self.tmp_program_statement_buffer.pop().unwrap();
-
-        if self.scope_depth != 1 {
-            self.referenced_idents.pop();
-
-            self.tmp_program_statement_buffer
-                .last_mut()
-                .unwrap()
-                .extend(statements);
-        } else {
-            self.tmp_program
-                .body
-                .splice(0..0, statements.into_iter().rev());
-        }
-
-        self.scope_depth -= 1;
-    }
+        let right_references = self.scopes.tagged_template_references(template);
 
-    fn visit_import_declaration(&mut self, it: &mut oxc_ast::ast::ImportDeclaration<'alloc>) {
-        if self.scan_pass
-            && let Some(specifiers) = &it.specifiers
+        // style objects are built at runtime, so they can use props and state
+        if self.style_objects
+            && self.style_function_names.contains(tag)
+            && right_references.iter().any(|reference| self.scopes.is_dynamic(reference))
         {
-            for specifier in specifiers {
-                if let ImportDeclarationSpecifier::ImportNamespaceSpecifier(namespace_spec) =
-                    specifier
-                {
-                    let remote_module_id = it.source.value.to_string();
-                    let namespace_name = namespace_spec.local.name.to_string();
-                    self.namespace_imports
-                        .insert(namespace_name, (remote_module_id, Default::default()));
-                }
-            }
+            return Some(self.build_style_template_output(template));
         }
-    }
 
-    fn visit_member_expression(&mut self, it: &mut oxc_ast::ast::MemberExpression<'alloc>) {
-        if !self.scan_pass
-            && let Some(property) = it.static_property_name()
-            && let Some(object) = it
-                .object()
-                .get_identifier_reference()
-                .map(|id| id.name.as_str())
-            && !self.variable_exists(object)
-            && let Some((_, remote_referenced_idents)) = self.namespace_imports.get_mut(object)
+        if self.check_dynamic_variable_access(&right_references, template.span().start)
+            || self.check_mutated_variable_access(&right_references)
         {
-            remote_referenced_idents.insert(property.to_string());
+            return None;
         }
 
-        oxc_ast_visit::walk_mut::walk_member_expression(self, it);
-    }
-
-    fn visit_expression(&mut self, it: &mut Expression<'alloc>) {
-        if self.error.is_some() {
-            return;
+        match binding {
+            Some(binding) => self.reference_variable(&self.scopes.binding_reference(binding)),
+            None => self.reference_variable(&Reference::unresolved(variable_name)),
         }
-        if self.scan_pass {
-            oxc_ast_visit::walk_mut::walk_expression(self, it);
-            return;
+        for reference in &right_references {
+            self.reference_variable(reference);
         }
-        if let Expression::TaggedTemplateExpression(template) = it
-            && let Some(tag) = utils::tagged_template_get_tag(template)
-            && (Some(tag) == self.css_function_name.as_deref()
-                || Some(tag) == self.style_function_name.as_deref())
-        {
-            oxc_ast_visit::walk_mut::walk_tagged_template_expression(self, template);
-
-            let span = template.span;
-            let variable_name = &format!("{PREFIX}_expression_{}", self.unique_number());
-
-            let right_references = utils::tagged_template_expression_get_references(template);
 
-            if self.check_dynamic_variable_access(&right_references, template.span().start) {
-                return;
-            }
+        let resolved_variable_name = match binding {
+            Some(binding) => self.resolve_binding_name(binding),
+            None => variable_name.to_string(),
+        };
 
-            self.reference_variable(variable_name.to_string());
-            for ident in right_references {
-                self.reference_variable(ident);
-            }
+        let replacement = match tag {
+            tag if self.css_function_names.contains(tag) => {
+                let class_name = class_name.clone().unwrap();
+                let extra_classes = self.extract_extra_classes(template);
+
+                self.insert_into_virtual_program_css(
+                    template,
+                    &resolved_variable_name,
+                    &class_name,
+                    extra_classes.names.clone(),
+                    CssBlockKind::Class,
+                );
 
-            let resolved_variable_name = self
-                .get_alias(variable_name)
-                .unwrap_or(variable_name)
-                .to_string();
+                let replacement = if self.atomic {
+                    // Atomic mode: Reference the imported atomic class from .style-this.js module
+                    // DON'T insert variable declarator into virtual program - the virtual program
+                    // only evaluates CSS templates, the actual variable is imported in main program
 
-            match tag {
-                tag if Some(tag) == self.css_function_name.as_deref() => {
-                    let class_name = self.create_virtual_css_template(variable_name);
-                    let extra_classes = self.extract_extra_classes(template);
+                    let base_name = resolved_variable_name.strip_prefix(&format!("{}_", crate::PREFIX))
+                        .unwrap_or(&resolved_variable_name);
+                    let import_ref_name = format!("_styleThis_{}", base_name);
                     
-                    self.insert_into_virtual_program_css(
-                        template,
-                        &resolved_variable_name,
-                        &class_name,
-                        extra_classes.clone(),
-                    );
-
-                    if self.atomic {
-                        // Atomic mode: Reference the imported atomic class from .style-this.js module
-                        // DON'T insert variable declarator into virtual program - that happens in main program
-                        // The virtual program only needs to evaluate CSS templates
-
-                        self.replacement_points.insert(
+                    // Build expression: _styleThisClasses._styleThis_xxx
+                    let member_expr = Expression::StaticMemberExpression(
+                        self.ast_builder.alloc_static_member_expression(
                             span,
-                            Expression::Identifier(self.ast_builder.alloc_identifier_reference(
-                                span,
-                                self.ast_builder.atom(&resolved_variable_name),
-                            )),
-                        );
-
-                        // Replace with identifier reference (evaluated later from .style-this.js)
-                        let base_name = resolved_variable_name.strip_prefix(&format!("{}_", crate::PREFIX))
-                            .unwrap_or(&resolved_variable_name);
-                        let import_ref_name = format!("_styleThis_{}", base_name);
-                        
-                        // Build expression: _styleThisClasses._styleThis_xxx
-                        let member_expr = Expression::StaticMemberExpression(
-                            self.ast_builder.alloc_static_member_expression(
-                                span,
-                                Expression::Identifier(
-                                    self.ast_builder.alloc_identifier_reference(
-                                        span,
-                                        self.ast_builder.atom("_styleThisClasses")
-                                    )
-                                ),
-                                self.ast_builder.identifier_name(span, self.ast_builder.atom(&import_ref_name)),
-                                false,
-                            )
-                        );
-                        
-                        // Handle extra classes - concatenate with space if present
-                        *it = if !extra_classes.is_empty() {
-                            let extra_class_string = extra_classes.join(" ");
-                            let space_and_extra = format!(" {}", extra_class_string);
-                            Expression::BinaryExpression(
-                                self.ast_builder.alloc_binary_expression(
+                            Expression::Identifier(
+                                self.ast_builder.alloc_identifier_reference(
                                     span,
-                                    member_expr,
-                                    oxc_ast::ast::BinaryOperator::Addition,
-                                    ast::build_string(self.ast_builder, span, &space_and_extra),
+                                    self.ast_builder.atom("_styleThisClasses")
                                 )
-                            )
-                        } else {
-                            member_expr
-                        };
-                    } else {
-                        // Non-atomic mode: inline the class name string
-                        // Build the full class list including generated class and extra classes
-                        let mut full_class_list = vec![class_name.clone()];
-                        full_class_list.extend(extra_classes);
-                        let full_class_string = full_class_list.join(" ");
-
-                        let variable_declarator = ast::build_variable_declarator(
-                            self.ast_builder,
-                            span,
-                            &resolved_variable_name,
-                            ast::build_decorated_string(self.ast_builder, span, &full_class_string),
-                        );
-
-                        self.insert_into_virtual_program(
-                            VirtualProgramInsert::VariableDeclarator(variable_declarator),
-                            None,
-                        );
-
-                        self.replacement_points.insert(
-                            span,
-                            Expression::Identifier(self.ast_builder.alloc_identifier_reference(
-                                span,
-                                self.ast_builder.atom(&resolved_variable_name),
-                            )),
-                        );
-
-                        *it = ast::build_string(self.ast_builder, span, &full_class_string);
+                            ),
+                            self.ast_builder.identifier_name(span, self.ast_builder.atom(&import_ref_name)),
+                            false,
+                        )
+                    );
+                    
+                    if top_level_const
+                        && extra_classes.names.is_empty()
+                        && extra_classes.expressions.is_empty()
+                    {
+                        // `cx` calls using the block are merged once its atomic classes are known
+                        self.atomic_blocks
+                            .insert(variable_name.to_string(), resolved_variable_name.clone());
                     }
-                }
-                tag if Some(tag) == self.style_function_name.as_deref() => {
-                    let mut quasis = template.quasi.quasis.clone_in(self.allocator);
-                    utils::trim_newlines(self.ast_builder, &mut quasis);
+
+                    // Handle extra classes - concatenate with space if present
+                    self.output_class_list(span, member_expr, &extra_classes, &resolved_variable_name)
+                } else {
+                    // Non-atomic mode: inline the class name string
+                    // Build the full class list including generated class and extra classes
+                    let class_name = ast::build_string(self.ast_builder, span, &class_name);
+                    let class_list = self.build_class_list(
+                        span,
+                        class_name.clone_in(self.allocator),
+                        &extra_classes,
+                        true,
+                    );
                     let variable_declarator = ast::build_variable_declarator(
                         self.ast_builder,
                         span,
                         &resolved_variable_name,
-                        self.ast_builder.expression_template_literal(
-                            span,
-                            quasis,
-                            template.quasi.expressions.clone_in(self.allocator),
-                        ),
+                        ast::build_new_string(self.ast_builder, span, class_list),
                     );
 
-                    self.style_variable_identifiers
-                        .insert(variable_name.to_string());
-
                     self.insert_into_virtual_program(
                         VirtualProgramInsert::VariableDeclarator(variable_declarator),
                         None,
                     );
 
-                    self.replacement_points.insert(
-                        span,
-                        Expression::Identifier(self.ast_builder.alloc_identifier_reference(
-                            span,
-                            self.ast_builder.atom(&resolved_variable_name),
-                        )),
-                    );
-
-                    *it = ast::build_undefined(self.ast_builder, span);
-                }
-                _ => {
-                    unreachable!()
-                }
-            };
-        };
-
-        oxc_ast_visit::walk_mut::walk_expression(self, it);
-    }
-
-    fn visit_variable_declarator(&mut self, it: &mut VariableDeclarator<'alloc>) {
-        if self.error.is_some() {
-            return;
-        }
-        if self.scan_pass {
-            let idents = binding_pattern_kind_get_idents(&it.id.kind);
-            for ident in idents {
-                let alias = if self.scope_depth == 1 {
-                    None
-                } else {
-                    Some(format!("{PREFIX}_var_{ident}_{}", self.unique_number()))
+                    self.output_class_list(span, class_name, &extra_classes, &resolved_variable_name)
                 };
 
-                self.aliases.last_mut().unwrap().insert(ident, alias);
-            }
-            oxc_ast_visit::walk_mut::walk_variable_declarator(self, it);
-            return;
-        }
-
-        let Some(init) = &mut it.init else {
-            return;
-        };
+                self.replacement_points.insert(
+                    span,
+                    Expression::Identifier(self.ast_builder.alloc_identifier_reference(
+                        span,
+                        self.ast_builder.atom(&resolved_variable_name),
+                    )),
+                );
 
-        if let Expression::TaggedTemplateExpression(template) = init
-            && let Some(tag) = utils::tagged_template_get_tag(template)
-            && (Some(tag) == self.css_function_name.as_deref()
-                || Some(tag) == self.style_function_name.as_deref())
-        {
-            let BindingPatternKind::BindingIdentifier(variable_name) = &it.id.kind else {
-                panic!("css variable declaration was not a regular variable declaration")
-            };
+                replacement
+            }
+            tag if self.style_function_names.contains(tag) => {
+                let mut quasis = template.quasi.quasis.clone_in(self.allocator);
+                utils::trim_newlines(self.ast_builder, &mut quasis);
+                let variable_declarator = ast::build_variable_declarator(
+                    self.ast_builder,
+                    span,
+                    &resolved_variable_name,
+                    self.ast_builder.expression_template_literal(
+                        span,
+                        quasis,
+                        template.quasi.expressions.clone_in(self.allocator),
+                    ),
+                );
 
-            oxc_ast_visit::walk_mut::walk_tagged_template_expression(self, template);
+                self.style_variable_identifiers
+                    .insert(variable_name.to_string());
 
-            let span = template.span;
-            let variable_name = variable_name.name.as_str();
+                self.insert_into_virtual_program(
+                    VirtualProgramInsert::VariableDeclarator(variable_declarator),
+                    None,
+                );
 
-            let right_references = utils::tagged_template_expression_get_references(template);
+                self.replacement_points.insert(
+                    span,
+                    Expression::Identifier(self.ast_builder.alloc_identifier_reference(
+                        span,
+                        self.ast_builder.atom(&resolved_variable_name),
+                    )),
+                );
 
-            if self.check_dynamic_variable_access(&right_references, template.span().start) {
-                return;
+                self.build_style_template_output(template)
+            }
+            tag if self.keyframes_function_names.contains(tag) => {
+                let animation_name = class_name.clone().unwrap();
+                self.insert_named_css_block(
+                    template,
+                    &resolved_variable_name,
+                    &animation_name,
+                    CssBlockKind::Keyframes,
+                );
+                ast::build_string(self.ast_builder, span, &animation_name)
+            }
+            tag if self.is_global_css_tag(tag) => {
+                let marker_name = class_name.clone().unwrap();
+                self.insert_named_css_block(
+                    template,
+                    &resolved_variable_name,
+                    &marker_name,
+                    CssBlockKind::Global,
+                );
+                ast::build_undefined(self.ast_builder, span)
             }
+            _ => {
+                unreachable!()
+            }
+        };
 
-            self.reference_variable(variable_name.to_string());
-            for ident in right_references {
-                self.reference_variable(ident);
+        if !dynamic_values.is_empty() {
+            let style = ast::build_custom_properties(self.ast_builder, span, dynamic_values);
+            // the style of a bound block is attached where the binding is used
+            match binding.and_then(|binding| self.scopes.binding_reference(binding).symbol) {
+                Some(symbol) => {
+                    let style_name = format!("{PREFIX}_{variable_name}_style");
+                    self.dynamic_styles
+                        .bindings
+                        .insert(symbol, (style_name, Some(style)));
+                }
+                None => {
+                    self.dynamic_styles.inline.insert(span, style);
+                }
             }
+        }
 
-            let resolved_variable_name = self
-                .get_alias(variable_name)
-                .unwrap_or(variable_name)
-                .to_string();
+        Some(replacement)
+    }
 
-            match tag {
-                tag if Some(tag) == self.css_function_name.as_deref() => {
-                    let class_name = self.create_virtual_css_template(variable_name);
-                    let extra_classes = self.extract_extra_classes(template);
+    /// emits a `keyframes` or `globalCss` template, whose variable holds the generated name
+    fn insert_named_css_block(
+        &mut self,
+        it: &TaggedTemplateExpression<'alloc>,
+        variable_name: &str,
+        name: &str,
+        kind: CssBlockKind,
+    ) {
+        let span = it.span;
 
-                    self.insert_into_virtual_program_css(
-                        template,
-                        &resolved_variable_name,
-                        &class_name,
-                        extra_classes.clone(),
-                    );
+        self.insert_into_virtual_program_css(it, variable_name, name, vec![], kind);
 
-                    if self.atomic {
-                        // Atomic mode: Reference the imported atomic class from .style-this.js module
-                        // DON'T insert variable declarator into virtual program - the virtual program
-                        // only evaluates CSS templates, the actual variable is imported in main program
-
-                        let base_name = resolved_variable_name.strip_prefix(&format!("{}_", crate::PREFIX))
-                            .unwrap_or(&resolved_variable_name);
-                        let import_ref_name = format!("_styleThis_{}", base_name);
-                        
-                        // Build expression: _styleThisClasses._styleThis_xxx
-                        let member_expr = Expression::StaticMemberExpression(
-                            self.ast_builder.alloc_static_member_expression(
-                                span,
-                                Expression::Identifier(
-                                    self.ast_builder.alloc_identifier_reference(
-                                        span,
-                                        self.ast_builder.atom("_styleThisClasses")
-                                    )
-                                ),
-                                self.ast_builder.identifier_name(span, self.ast_builder.atom(&import_ref_name)),
-                                false,
-                            )
-                        );
-                        
-                        // Handle extra classes - concatenate with space if present
-                        *init = if !extra_classes.is_empty() {
-                            let extra_class_string = extra_classes.join(" ");
-                            let space_and_extra = format!(" {}", extra_class_string);
-                            Expression::BinaryExpression(
-                                self.ast_builder.alloc_binary_expression(
-                                    span,
-                                    member_expr,
-                                    oxc_ast::ast::BinaryOperator::Addition,
-                                    ast::build_string(self.ast_builder, span, &space_and_extra),
-                                )
-                            )
-                        } else {
-                            member_expr
-                        };
+        // atomic mode declares the variables of all templates before evaluating the program
+        if !self.atomic {
+            let variable_declarator = ast::build_variable_declarator(
+                self.ast_builder,
+                span,
+                variable_name,
+                ast::build_decorated_string(self.ast_builder, span, name),
+            );
+            self.insert_into_virtual_program(
+                VirtualProgramInsert::VariableDeclarator(variable_declarator),
+                None,
+            );
+        }
 
-                        self.replacement_points.insert(
-                            span,
-                            Expression::Identifier(self.ast_builder.alloc_identifier_reference(
-                                span,
-                                self.ast_builder.atom(&resolved_variable_name),
-                            )),
-                        );
-                    } else {
-                        // Non-atomic mode: inline the class name string
-                        let mut full_class_list = vec![class_name.clone()];
-                        full_class_list.extend(extra_classes.clone());
-                        let full_class_string = full_class_list.join(" ");
-
-                        let variable_declarator = ast::build_variable_declarator(
-                            self.ast_builder,
-                            span,
-                            &resolved_variable_name,
-                            ast::build_decorated_string(self.ast_builder, span, &full_class_string),
-                        );
+        self.replacement_points.insert(
+            span,
+            Expression::Identifier(
+                self.ast_builder
+                    .alloc_identifier_reference(span, self.ast_builder.atom(variable_name)),
+            ),
+        );
+    }
 
-                        self.insert_into_virtual_program(
-                            VirtualProgramInsert::VariableDeclarator(variable_declarator),
-                            None,
-                        );
+    /// `globalCss`...`` or `css.global`...``
+    fn is_global_css_tag(&self, tag: &str) -> bool {
+        self.global_css_function_names.contains(tag)
+            || tag
+                .strip_suffix(".global")
+                .is_some_and(|css| self.css_function_names.contains(css))
+    }
 
-                        self.replacement_points.insert(
-                            span,
-                            Expression::Identifier(self.ast_builder.alloc_identifier_reference(
-                                span,
-                                self.ast_builder.atom(&resolved_variable_name),
-                            )),
-                        );
+    /// tags of the templates compiled by `compile_tagged_template`
+    fn is_template_tag(&self, tag: &str) -> bool {
+        self.css_function_names.contains(tag)
+            || self.style_function_names.contains(tag)
+            || self.keyframes_function_names.contains(tag)
+            || self.is_global_css_tag(tag)
+    }
+
+    /// tags and callees of the templates and calls compiled here
+    fn is_style_call(&self, path: &str) -> bool {
+        [
+            &self.css_function_names,
+            &self.style_function_names,
+            &self.keyframes_function_names,
+            &self.create_tokens_function_names,
+        ]
+        .iter()
+        .any(|names| names.contains(path))
+            || self.is_global_css_tag(path)
+    }
+
+    /// builds the CSS of a template in Rust if every interpolation is known at compile time
+    fn fold_css_template(
+        &self,
+        quasis: &[oxc_ast::ast::TemplateElement<'alloc>],
+        expressions: &[Expression<'alloc>],
+    ) -> Option<String> {
+        let mut css = String::new();
+        for (idx, quasi) in quasis.iter().enumerate() {
+            // escapes are left to the JS engine
+            if quasi.value.raw.contains('\\') {
+                return None;
+            }
+            css.push_str(&quasi.value.raw);
+            if let Some(expression) = expressions.get(idx) {
+                let value = self.fold_static(expression)?;
+                css.push_str(&value.to_js_string()?);
+            }
+        }
+        Some(css)
+    }
+
+    /// folds an expression using the top-level consts of this file it reads
+    fn fold_static(&self, expression: &Expression<'alloc>) -> Option<StaticValue> {
+        // names resolving to locals shadow the consts of the same name
+        let shadowed = self
+            .scopes
+            .expression_references(expression)
+            .into_iter()
+            .filter(|reference| self.scopes.is_local(reference))
+            .map(|reference| reference.name)
+            .collect::<HashSet<_>>();
+
+        fold_expression(expression, &|name| {
+            if shadowed.contains(name) {
+                return None;
+            }
+            self.static_consts.get(name).cloned()
+        })
+    }
+
+    /// replaces interpolations depending on props or local state with `var(--...)` and
+    /// returns the custom property names along with the expressions providing their values
+    fn extract_dynamic_interpolations(
+        &self,
+        template: &mut TaggedTemplateExpression<'alloc>,
+        class_name: &str,
+    ) -> Vec<(String, Expression<'alloc>)> {
+        let mut dynamic_values = vec![];
+
+        for expression in template.quasi.expressions.iter_mut() {
+            // only the dynamic values of style objects are replaced
+            if let Expression::CallExpression(call) = expression
+                && matches!(&call.callee, Expression::Identifier(callee) if callee.name == object_to_css_function_name())
+                && let Some(Expression::ObjectExpression(object)) =
+                    call.arguments.first_mut().and_then(|argument| argument.as_expression_mut())
+            {
+                self.extract_dynamic_object_values(object, class_name, &mut dynamic_values);
+                continue;
+            }
+
+            // extraClass calls are not part of the CSS
+            if let Expression::CallExpression(call) = expression
+                && is_call_to(&self.extra_class_function_names, &call.callee)
+            {
+                continue;
+            }
+
+            if !self.is_dynamic_expression(expression) {
+                continue;
+            }
+
+            self.replace_with_custom_property(expression, class_name, &mut dynamic_values);
+        }
+
+        dynamic_values
+    }
+
+    fn extract_dynamic_object_values(
+        &self,
+        object: &mut oxc_ast::ast::ObjectExpression<'alloc>,
+        class_name: &str,
+        dynamic_values: &mut Vec<(String, Expression<'alloc>)>,
+    ) {
+        for property in object.properties.iter_mut() {
+            let oxc_ast::ast::ObjectPropertyKind::ObjectProperty(property) = property else {
+                continue;
+            };
+
+            if let Expression::ObjectExpression(nested) = &mut property.value {
+                self.extract_dynamic_object_values(nested, class_name, dynamic_values);
+                continue;
+            }
+
+            if self.is_dynamic_expression(&property.value) {
+                self.replace_with_custom_property(&mut property.value, class_name, dynamic_values);
+            }
+        }
+    }
+
+    fn replace_with_custom_property(
+        &self,
+        expression: &mut Expression<'alloc>,
+        class_name: &str,
+        dynamic_values: &mut Vec<(String, Expression<'alloc>)>,
+    ) {
+        let span = expression.span();
+        let custom_property = format!("--{class_name}-{}", dynamic_values.len() + 1);
+        let value = std::mem::replace(
+            expression,
+            ast::build_string(self.ast_builder, span, &format!("var({custom_property})")),
+        );
+        dynamic_values.push((custom_property, value));
+    }
+
+    /// rewrites `css({...})` into `css`${objectToCss({...})}``, so style objects share the
+    /// code paths of templates
+    fn convert_css_object_call(&self, expression: &mut Expression<'alloc>) {
+        let Expression::CallExpression(call) = expression else {
+            return;
+        };
+        if call.arguments.len() != 1
+            || call.arguments[0].is_spread()
+            || !is_call_to(&self.css_function_names, &call.callee)
+        {
+            return;
+        }
+
+        let span = call.span;
+        let Expression::CallExpression(call) =
+            std::mem::replace(expression, ast::build_undefined(self.ast_builder, span))
+        else {
+            unreachable!()
+        };
+        let call = call.unbox();
+
+        let object_to_css = self.ast_builder.expression_call(
+            span,
+            ast::build_identifier(self.ast_builder, span, &object_to_css_function_name()),
+            None as Option<oxc_allocator::Box<_>>,
+            call.arguments,
+            false,
+        );
+        let quasi = |tail| oxc_ast::ast::TemplateElement {
+            span,
+            tail,
+            value: oxc_ast::ast::TemplateElementValue {
+                raw: self.ast_builder.atom(""),
+                cooked: Some(self.ast_builder.atom("")),
+            },
+            lone_surrogates: false,
+        };
+
+        *expression = self.ast_builder.expression_tagged_template(
+            span,
+            call.callee,
+            None as Option<oxc_allocator::Box<_>>,
+            self.ast_builder.template_literal(
+                span,
+                self.ast_builder.vec_from_iter([quasi(false), quasi(true)]),
+                self.ast_builder.vec1(object_to_css),
+            ),
+        );
+    }
+
+    /// compiles `createTokens({...})` into a `:root` block declaring a custom property per token
+    /// and replaces the call with the same object holding `var(--...)` references
+    /// returns whether the expression was a `createTokens` call
+    fn compile_create_tokens(
+        &mut self,
+        expression: &mut Expression<'alloc>,
+        variable_name: Option<&str>,
+    ) -> bool {
+        let Expression::CallExpression(call) = expression else {
+            return false;
+        };
+        if !is_call_to(&self.create_tokens_function_names, &call.callee) {
+            return false;
+        }
+
+        let span = call.span;
+        if call.arguments.len() != 1
+            || !matches!(call.arguments[0], oxc_ast::ast::Argument::ObjectExpression(_))
+        {
+            self.set_unsupported_syntax_error("createTokens expects an object literal", span);
+            return true;
+        }
+
+        let Expression::CallExpression(call) =
+            std::mem::replace(expression, ast::build_undefined(self.ast_builder, span))
+        else {
+            unreachable!()
+        };
+        let call = call.unbox();
+        let Some(oxc_ast::ast::Argument::ObjectExpression(mut tokens)) =
+            call.arguments.into_iter().next()
+        else {
+            unreachable!()
+        };
+
+        let variable_name = match variable_name {
+            Some(variable_name) => variable_name.to_string(),
+            None => format!("{PREFIX}_expression_{}", self.unique_number()),
+        };
+        let name = self.create_virtual_css_template(&variable_name, span);
+        let mut properties = vec![];
+        let mut token_paths = HashMap::new();
+        if let Err(message) =
+            self.replace_token_values(&mut tokens, &name, "", &mut properties, &mut token_paths)
+        {
+            self.set_unsupported_syntax_error(&message, span);
+            return true;
+        }
+
+        *expression = Expression::ObjectExpression(tokens);
+        if properties.is_empty() {
+            return true;
+        }
+
+        // `:root { --name-path: ${value}; ... }`
+        let quasi = |raw: String, tail| oxc_ast::ast::TemplateElement {
+            span,
+            tail,
+            value: oxc_ast::ast::TemplateElementValue {
+                raw: self.ast_builder.atom(&raw),
+                cooked: Some(self.ast_builder.atom(&raw)),
+            },
+            lone_surrogates: false,
+        };
+        let mut quasis = self.ast_builder.vec();
+        let mut expressions = self.ast_builder.vec();
+        let mut raw = ":root {\n".to_string();
+        for (custom_property, value) in properties {
+            raw.push_str(&format!("{custom_property}: "));
+            quasis.push(quasi(raw, false));
+            expressions.push(value);
+            raw = ";\n".to_string();
+        }
+        raw.push('}');
+        quasis.push(quasi(raw, true));
+        let template_literal = self.ast_builder.template_literal(span, quasis, expressions);
+
+        let references = self.scopes.expression_references(&Expression::TemplateLiteral(
+            self.ast_builder.alloc(template_literal.clone_in(self.allocator)),
+        ));
+        if self.check_dynamic_variable_access(&references, span.start) {
+            return true;
+        }
+
+        let block_variable_name = format!("{PREFIX}_expression_{}", self.unique_number());
+        self.reference_variable(&Reference::unresolved(&block_variable_name));
+        for reference in &references {
+            self.reference_variable(reference);
+        }
+
+        let template = self.ast_builder.tagged_template_expression(
+            span,
+            call.callee,
+            None as Option<oxc_allocator::Box<_>>,
+            template_literal,
+        );
+        self.insert_named_css_block(
+            &template,
+            &block_variable_name,
+            &name,
+            CssBlockKind::Global,
+        );
+        // the object itself is copied into the virtual program, not the CSS block
+        self.replacement_points.remove(&span);
+        true
+    }
+
+    /// replaces token values with `var(--...)` strings, collecting the custom properties and
+    /// the original values, returns the error message if the object can't be compiled
+    ///
+    /// `token_paths` maps the custom properties to the tokens declaring them, keys like `a.b`
+    /// and `a-b` are both sanitized to `a-b` and can't share a custom property
+    fn replace_token_values(
+        &self,
+        object: &mut oxc_ast::ast::ObjectExpression<'alloc>,
+        prefix: &str,
+        path: &str,
+        properties: &mut Vec<(String, Expression<'alloc>)>,
+        token_paths: &mut HashMap<String, String>,
+    ) -> Result<(), String> {
+        let unsupported = || "createTokens expects static keys and no spreads or methods";
+        for property in object.properties.iter_mut() {
+            let oxc_ast::ast::ObjectPropertyKind::ObjectProperty(property) = property else {
+                return Err(unsupported().to_string());
+            };
+            if property.kind != oxc_ast::ast::PropertyKind::Init || property.method {
+                return Err(unsupported().to_string());
+            }
+            let key = match &property.key {
+                oxc_ast::ast::PropertyKey::StaticIdentifier(ident) => ident.name.to_string(),
+                oxc_ast::ast::PropertyKey::StringLiteral(literal) => literal.value.to_string(),
+                oxc_ast::ast::PropertyKey::NumericLiteral(literal) => literal.value.to_string(),
+                _ => return Err(unsupported().to_string()),
+            };
+            let token_path = format!("{path}[{key:?}]");
+            let key: String = key
+                .chars()
+                .map(|ch| if ch.is_ascii_alphanumeric() || ch == '_' { ch } else { '-' })
+                .collect();
+            let name = format!("{prefix}-{key}");
+
+            if let Expression::ObjectExpression(nested) = &mut property.value {
+                self.replace_token_values(nested, &name, &token_path, properties, token_paths)?;
+                continue;
+            }
 
-                        *init = ast::build_string(self.ast_builder, span, &full_class_string);
+            let custom_property = format!("--{name}");
+            if let Some(existing) = token_paths.insert(custom_property.clone(), token_path.clone())
+            {
+                return Err(format!(
+                    "createTokens tokens {existing} and {token_path} both map to {custom_property}, rename one of them"
+                ));
+            }
+
+            let span = property.value.span();
+            let value = std::mem::replace(
+                &mut property.value,
+                ast::build_string(self.ast_builder, span, &format!("var(--{name})")),
+            );
+            properties.push((custom_property, value));
+        }
+        Ok(())
+    }
+
+    fn set_unsupported_syntax_error(&mut self, message: &str, span: Span) {
+        let (row, column) = get_pos_from_offset(self.program_code, span.start as usize);
+        self.error = Some(TransformError::UnsupportedSyntax {
+            message: message.to_string(),
+            filepath: self.program_filepath.to_string(),
+            row,
+            column,
+        });
+    }
+
+    /// extracts extraClass("a b c") calls from template expressions
+    fn extract_extra_classes(&self, it: &TaggedTemplateExpression<'alloc>) -> ExtraClasses<'alloc> {
+        let mut extra_classes = ExtraClasses {
+            names: Vec::new(),
+            expressions: Vec::new(),
+        };
+
+        for expr in &it.quasi.expressions {
+            if let Expression::CallExpression(call) = expr
+                && is_call_to(&self.extra_class_function_names, &call.callee)
+            {
+                for arg in &call.arguments {
+                    let Some(arg) = arg.as_expression() else {
+                        continue;
+                    };
+
+                    // literals and consts of this file are known now, anything else
+                    // (imports, member accesses) is left to the evaluation
+                    match self.fold_static(arg)
+                        .and_then(|value| value.to_js_string())
+                    {
+                        Some(classes) => extra_classes
+                            .names
+                            .extend(classes.split_whitespace().map(str::to_string)),
+                        None => extra_classes.expressions.push(arg.clone_in(self.allocator)),
                     }
                 }
-                tag if Some(tag) == self.style_function_name.as_deref() => {
-                    let mut quasis = template.quasi.quasis.clone_in(self.allocator);
-                    utils::trim_newlines(self.ast_builder, &mut quasis);
-                    let variable_declarator = ast::build_variable_declarator(
-                        self.ast_builder,
-                        span,
-                        &resolved_variable_name,
-                        self.ast_builder.expression_template_literal(
-                            span,
-                            quasis,
-                            template.quasi.expressions.clone_in(self.allocator),
-                        ),
-                    );
+            }
+        }
 
-                    self.style_variable_identifiers
-                        .insert(variable_name.to_string());
+        extra_classes
+    }
 
-                    self.insert_into_virtual_program(
-                        VirtualProgramInsert::VariableDeclarator(variable_declarator),
-                        None,
-                    );
+    /// what a `style` template is replaced with in the output, a style object in React mode
+    /// and `undefined` otherwise since its CSS is only interpolated into css templates
+    fn build_style_template_output(
+        &mut self,
+        template: &TaggedTemplateExpression<'alloc>,
+    ) -> Expression<'alloc> {
+        let span = template.span;
+        if !self.style_objects {
+            return ast::build_undefined(self.ast_builder, span);
+        }
 
-                    self.replacement_points.insert(
-                        span,
-                        Expression::Identifier(self.ast_builder.alloc_identifier_reference(
-                            span,
-                            self.ast_builder.atom(&resolved_variable_name),
-                        )),
-                    );
+        let mut parts = vec![];
+        for (index, quasi) in template.quasi.quasis.iter().enumerate() {
+            let text = quasi.value.cooked.unwrap_or(quasi.value.raw);
+            parts.push(StylePart::Text(text.to_string()));
 
-                    *init = ast::build_undefined(self.ast_builder, span);
-                }
-                _ => {
-                    unreachable!()
+            let Some(expression) = template.quasi.expressions.get(index) else {
+                continue;
+            };
+            // interpolations known at compile time become part of the static text
+            match self.fold_static(expression)
+                .and_then(|value| value.to_js_string())
+            {
+                Some(text) => parts.push(StylePart::Text(text)),
+                None => parts.push(StylePart::Expression(index)),
+            }
+        }
+
+        match parse_style_template(parts) {
+            Ok(entries) => build_style_object(
+                self.ast_builder,
+                self.allocator,
+                span,
+                entries,
+                &template.quasi.expressions,
+            ),
+            Err(message) => {
+                let (row, column) = get_pos_from_offset(self.program_code, span.start as usize);
+                self.error = Some(TransformError::UnsupportedSyntax {
+                    message,
+                    filepath: self.program_filepath.to_string(),
+                    row,
+                    column,
+                });
+                ast::build_undefined(self.ast_builder, span)
+            }
+        }
+    }
+
+    /// `class_name` followed by the extra classes, e.g. `"btn-abc123 card " + utilities.card`
+    fn build_class_list(
+        &self,
+        span: Span,
+        class_name: Expression<'alloc>,
+        extra_classes: &ExtraClasses<'alloc>,
+        aliased: bool,
+    ) -> Expression<'alloc> {
+        let concat = |left, right| ast::build_string_concat(self.ast_builder, span, left, right);
+
+        let mut class_list = class_name;
+        for name in &extra_classes.names {
+            let name = ast::build_string(self.ast_builder, span, &format!(" {name}"));
+            class_list = concat(class_list, name);
+        }
+
+        for expression in &extra_classes.expressions {
+            let mut expression = expression.clone_in(self.allocator);
+            if aliased {
+                self.scopes
+                    .alias_replacer(self.ast_builder)
+                    .visit_expression(&mut expression);
+            }
+
+            class_list = concat(class_list, ast::build_string(self.ast_builder, span, " "));
+            class_list = concat(class_list, expression);
+        }
+
+        class_list
+    }
+
+    /// the class list of a css block in the output, extra classes only known once evaluated
+    /// are a placeholder string replaced with their evaluated value (see `finish`), so the
+    /// output doesn't run the expressions again
+    fn output_class_list(
+        &mut self,
+        span: Span,
+        class_name: Expression<'alloc>,
+        extra_classes: &ExtraClasses<'alloc>,
+        variable_name: &str,
+    ) -> Expression<'alloc> {
+        if extra_classes.expressions.is_empty() {
+            return self.build_class_list(span, class_name, extra_classes, false);
+        }
+
+        let placeholder = format!("{PREFIX}_class_list_{}", self.unique_number());
+        let placeholder_string = ast::build_string(self.ast_builder, span, &placeholder);
+        if !self.atomic {
+            // the virtual program's `new String(...)` holds the whole class list
+            self.evaluated_class_lists
+                .insert(placeholder, format!("String({variable_name})"));
+            return placeholder_string;
+        }
+
+        // atomic classes are only known to the `.style-this.js` module, the evaluated extra
+        // classes are appended to it
+        let extra_classes_name = format!("{variable_name}_extraClasses");
+        let evaluated_extra_classes = self.build_class_list(
+            span,
+            ast::build_string(self.ast_builder, span, ""),
+            &ExtraClasses {
+                names: vec![],
+                expressions: extra_classes
+                    .expressions
+                    .iter()
+                    .map(|expression| expression.clone_in(self.allocator))
+                    .collect(),
+            },
+            true,
+        );
+        let variable_declarator = ast::build_variable_declarator(
+            self.ast_builder,
+            span,
+            &extra_classes_name,
+            evaluated_extra_classes,
+        );
+        self.insert_into_virtual_program(
+            VirtualProgramInsert::VariableDeclarator(variable_declarator),
+            None,
+        );
+        self.evaluated_class_lists
+            .insert(placeholder, format!("String({extra_classes_name})"));
+
+        let class_list = self.build_class_list(
+            span,
+            class_name,
+            &ExtraClasses {
+                names: extra_classes.names.clone(),
+                expressions: vec![],
+            },
+            false,
+        );
+        ast::build_string_concat(self.ast_builder, span, class_list, placeholder_string)
+    }
+
+    fn unique_number(&mut self) -> u32 {
+        self.unique_number_counter += 1;
+        self.unique_number_counter
+    }
+
+}
+
+impl<'a, 'alloc> VisitMut<'alloc> for VisitorTransformer<'a, 'alloc> {
+    // move backwards through statements, so declarations know whether anything after them
+    // references them
+    fn visit_statements(&mut self, it: &mut oxc_allocator::Vec<'alloc, Statement<'alloc>>) {
+        if self.error.is_some() {
+            return;
+        }
+
+        self.function_declarations.push(vec![]);
+        for el in it.iter_mut().rev() {
+            self.visit_statement(el);
+        }
+
+        // hoisted functions can be referenced from anywhere in the block, the ones visited
+        // last have the highest positions and are inserted first to keep the others in place
+        let function_declarations = self.function_declarations.pop().unwrap();
+        for (pos, function_declaration) in function_declarations.into_iter().rev() {
+            self.insert_function_declaration(function_declaration, pos);
+        }
+    }
+
+    fn enter_scope(
+        &mut self,
+        _flags: oxc_semantic::ScopeFlags,
+        _scope_id: &std::cell::Cell<Option<oxc_semantic::ScopeId>>,
+    ) {
+        self.scope_depth += 1;
+        self.tmp_program_statement_buffer.push(Default::default());
+    }
+
+    fn leave_scope(&mut self) {
+        let statements = self.tmp_program_statement_buffer.pop().unwrap();
+
+        if self.scope_depth != 1 {
+            self.tmp_program_statement_buffer
+                .last_mut()
+                .unwrap()
+                .extend(statements);
+        } else {
+            self.tmp_program
+                .body
+                .splice(0..0, statements.into_iter().rev());
+        }
+
+        self.scope_depth -= 1;
+    }
+
+    fn visit_program(&mut self, it: &mut Program<'alloc>) {
+        // names of removed or renamed blocks are free again, the others are claimed again
+        if self.entrypoint {
+            let filepath = self.program_filepath;
+            CLASS_NAME_OWNERS.with(|owners| {
+                owners.borrow_mut().retain(|_, owner| owner != filepath)
+            });
+        }
+        self.binding_scopes = BindingScopes::collect(it);
+        self.scopes = ScopeAnalysis::build(it, &|path| self.is_style_call(path));
+
+        for statement in &it.body {
+            let Statement::ImportDeclaration(import) = statement else {
+                continue;
+            };
+            for specifier in import.specifiers.iter().flatten() {
+                if let ImportDeclarationSpecifier::ImportNamespaceSpecifier(namespace_spec) =
+                    specifier
+                {
+                    self.namespace_imports.insert(
+                        namespace_spec.local.name.to_string(),
+                        (import.source.value.to_string(), Default::default()),
+                    );
                 }
+            }
+        }
+
+        // what importers use is referenced before any declaration is visited
+        for name in self.referenced_idents.clone() {
+            if let Some(symbol) = self.scopes.root_symbol(&name) {
+                self.reference_dependencies(symbol);
+            }
+        }
+        if self.referenced_idents.contains(DEFAULT_EXPORT_SENTINEL) {
+            let references = it
+                .body
+                .iter()
+                .filter_map(|statement| match statement {
+                    Statement::ExportDefaultDeclaration(export) => {
+                        Some(self.scopes.export_default_references(export))
+                    }
+                    _ => None,
+                })
+                .flatten()
+                .collect::<Vec<_>>();
+            for reference in &references {
+                self.reference_variable(reference);
+            }
+        }
+
+        oxc_ast_visit::walk_mut::walk_program(self, it);
+
+        if let Err(span) = attach_dynamic_styles(
+            self.ast_builder,
+            it,
+            &self.scopes,
+            std::mem::take(&mut self.dynamic_styles),
+        ) {
+            self.set_unsupported_syntax_error(
+                "css templates using props or state can only be used in the className or class of JSX elements",
+                span,
+            );
+            return;
+        }
+
+        // cx calls can use css bindings declared further down, which are only known now
+        let resolve = |name: &str| {
+            self.class_lists
+                .get(name)
+                .map(|class_list| StaticValue::String(class_list.clone()))
+                .or_else(|| self.static_consts.get(name).cloned())
+        };
+        let atomic_block = |name: &str| self.atomic_blocks.get(name).cloned();
+        let evaluated_cx_calls = compile_cx_calls(
+            self.ast_builder,
+            self.allocator,
+            it,
+            &self.cx_calls,
+            &resolve,
+            self.atomic,
+            &atomic_block,
+        );
+        self.evaluated_class_lists.extend(evaluated_cx_calls);
+    }
+
+    fn visit_member_expression(&mut self, it: &mut oxc_ast::ast::MemberExpression<'alloc>) {
+        if let Some(property) = it.static_property_name()
+            && let Some(object) = it.object().get_identifier_reference()
+            // a local binding can shadow the namespace
+            && !self.scopes.is_local(&self.scopes.identifier_reference(object))
+            && let Some((_, remote_referenced_idents)) =
+                self.namespace_imports.get_mut(object.name.as_str())
+        {
+            remote_referenced_idents.insert(property.to_string());
+        }
+
+        oxc_ast_visit::walk_mut::walk_member_expression(self, it);
+    }
+
+    fn visit_expression(&mut self, it: &mut Expression<'alloc>) {
+        if self.error.is_some() {
+            return;
+        }
+        self.compile_create_tokens(it, None);
+        self.convert_css_object_call(it);
+        if let Expression::CallExpression(call) = it
+            && is_call_to(&self.cx_function_names, &call.callee)
+        {
+            let top_level_bindings = call
+                .arguments
+                .iter()
+                .filter_map(|argument| argument.as_expression())
+                .flat_map(|argument| self.scopes.expression_references(argument))
+                .filter(|reference| !self.scopes.is_local(reference))
+                .map(|reference| reference.name)
+                .collect();
+            self.cx_calls.insert(call.span, top_level_bindings);
+        }
+        if let Expression::TaggedTemplateExpression(template) = it
+            && let Some(tag) = utils::tagged_template_get_tag(template).as_deref()
+            && self.is_template_tag(tag)
+        {
+            if let Some(replacement) = self.compile_tagged_template(template, tag, None, false) {
+                *it = replacement;
+            }
+            return;
+        }
+
+        oxc_ast_visit::walk_mut::walk_expression(self, it);
+    }
+
+    fn visit_variable_declarator(&mut self, it: &mut VariableDeclarator<'alloc>) {
+        if self.error.is_some() {
+            return;
+        }
+
+        let Some(init) = &mut it.init else {
+            // `let size;` assigned later on, see `visit_expression_statement`
+            if let BindingPatternKind::BindingIdentifier(ident) = &it.id.kind
+                && self.is_binding_referenced(ident)
+                && !self.scopes.is_dynamic(&self.scopes.binding_reference(ident))
+            {
+                let mut variable_declarator = it.clone_in(self.allocator);
+                self.scopes
+                    .alias_replacer(self.ast_builder)
+                    .visit_variable_declarator(&mut variable_declarator);
+                self.insert_into_virtual_program(
+                    VirtualProgramInsert::VariableDeclarator(variable_declarator),
+                    None,
+                );
+            }
+            return;
+        };
+
+        if let BindingPatternKind::BindingIdentifier(ident) = &it.id.kind
+            && self.compile_create_tokens(init, Some(&ident.name))
+            // tokens are plain strings now, so top-level templates using them can be folded
+            && self.scope_depth == 1
+            && it.kind == VariableDeclarationKind::Const
+            && let Some(tokens) = fold_expression(init, &|_| None)
+        {
+            self.static_consts.insert(ident.name.to_string(), tokens);
+        }
+        self.convert_css_object_call(init);
+        if let Expression::TaggedTemplateExpression(template) = init
+            && let Some(tag) = utils::tagged_template_get_tag(template).as_deref()
+            && self.is_template_tag(tag)
+            // destructured templates are handled like any other expression below
+            && let BindingPatternKind::BindingIdentifier(binding) = &it.id.kind
+        {
+            let top_level_const = self.scope_depth == 1 && it.kind == VariableDeclarationKind::Const;
+            let Some(replacement) =
+                self.compile_tagged_template(template, tag, Some(binding), top_level_const)
+            else {
+                return;
             };
+            *init = replacement;
+
+            if top_level_const
+                && let Expression::StringLiteral(class_list) = init
+                && !self.evaluated_class_lists.contains_key(class_list.value.as_str())
+            {
+                self.class_lists
+                    .insert(binding.name.to_string(), class_list.value.to_string());
+            }
 
             return;
         };
@@ -1061,26 +1788,24 @@ impl<'a, 'alloc> VisitMut<'alloc> for VisitorTransformer<'a, 'alloc> {
 
         let Some(init) = &it.init else { return };
 
-        let variable_names = binding_pattern_kind_get_idents(&it.id.kind);
-
-        let referenced_variable_names: Vec<_> = variable_names
+        let is_referenced = it
+            .id
+            .get_binding_identifiers()
             .iter()
-            .filter(|name| self.is_variable_referenced(name))
-            .collect();
-
-        if referenced_variable_names.is_empty() {
+            .any(|ident| self.is_binding_referenced(ident));
+        if !is_referenced {
             return;
         }
 
         let span = it.span;
-        let right_references = utils::expression_get_references(init);
+        let right_references = self.scopes.expression_references(init);
 
         if self.check_dynamic_variable_access(&right_references, init.span().start) {
             return;
         }
 
-        for ident in right_references {
-            self.reference_variable(ident);
+        for reference in &right_references {
+            self.reference_variable(reference);
         }
 
         let mut right = init.clone_in(self.allocator);
@@ -1091,12 +1816,10 @@ impl<'a, 'alloc> VisitMut<'alloc> for VisitorTransformer<'a, 'alloc> {
             &mut self.replacement_points,
         );
 
-        replace_in_expression_using_identifiers(self.ast_builder, &mut right, &|name| {
-            self.get_alias(name).map(|v| v.to_string())
-        });
-
         let mut aliased_idents = it.id.kind.clone_in(self.allocator);
-        self.alias_binding_pattern(&mut aliased_idents);
+        let mut alias_replacer = self.scopes.alias_replacer(self.ast_builder);
+        alias_replacer.visit_expression(&mut right);
+        alias_replacer.visit_binding_pattern_kind(&mut aliased_idents);
 
         let variable_declarator = ast::build_variable_declarator_pattern(
             self.ast_builder,
@@ -1131,33 +1854,23 @@ impl<'a, 'alloc> VisitMut<'alloc> for VisitorTransformer<'a, 'alloc> {
             return;
         }
 
-        if let Statement::FunctionDeclaration(function) = it {
-            self.visit_function_declaration(function, ScopeFlags::Function);
-        }
-
-        if let Statement::ClassDeclaration(class) = it {
-            self.visit_class_declaration(class);
+        match it {
+            Statement::FunctionDeclaration(function) => {
+                self.visit_function_declaration(function, ScopeFlags::Function)
+            }
+            Statement::ClassDeclaration(class) => self.visit_class_declaration(class),
+            _ => oxc_ast_visit::walk_mut::walk_statement(self, it),
         }
-
-        oxc_ast_visit::walk_mut::walk_statement(self, it);
     }
 
     fn visit_export_default_declaration(&mut self, it: &mut ExportDefaultDeclaration<'alloc>) {
         if self.error.is_some() {
             return;
         }
-        if self.scan_pass {
-            return;
-        }
 
-        let global_sentinel = "__global__export__";
+        let global_sentinel = DEFAULT_EXPORT_SENTINEL;
 
-        if !self
-            .referenced_idents
-            .first()
-            .unwrap()
-            .contains(global_sentinel)
-        {
+        if !self.referenced_idents.contains(global_sentinel) {
             oxc_ast_visit::walk_mut::walk_export_default_declaration(self, it);
             return;
         }
@@ -1223,11 +1936,6 @@ impl<'a, 'alloc> VisitMut<'alloc> for VisitorTransformer<'a, 'alloc> {
             return;
         }
 
-        // TODO handle correctly
-        if self.scan_pass {
-            return;
-        }
-
         let Some(declaration) = &mut it.declaration else {
             return;
         };
@@ -1243,7 +1951,7 @@ impl<'a, 'alloc> VisitMut<'alloc> for VisitorTransformer<'a, 'alloc> {
                     let idents = binding_pattern_kind_get_idents(&decl.id.kind);
                     self.exported_idents.extend(
                         idents.into_iter().filter(|ident| {
-                            self.referenced_idents.first().unwrap().contains(ident)
+                            self.referenced_idents.contains(ident)
                         }),
                     );
                 }
@@ -1257,7 +1965,7 @@ impl<'a, 'alloc> VisitMut<'alloc> for VisitorTransformer<'a, 'alloc> {
                     .name
                     .as_str();
 
-                if self.referenced_idents.first().unwrap().contains(name) {
+                if self.referenced_idents.contains(name) {
                     self.exported_idents.insert(name.to_string());
                 }
 
@@ -1273,7 +1981,7 @@ impl<'a, 'alloc> VisitMut<'alloc> for VisitorTransformer<'a, 'alloc> {
                     .name
                     .as_str();
 
-                if self.referenced_idents.first().unwrap().contains(name) {
+                if self.referenced_idents.contains(name) {
                     self.exported_idents.insert(name.to_string());
                 }
 
@@ -1284,15 +1992,122 @@ impl<'a, 'alloc> VisitMut<'alloc> for VisitorTransformer<'a, 'alloc> {
         }
     }
 
-    fn visit_formal_parameter(&mut self, it: &mut oxc_ast::ast::FormalParameter<'alloc>) {
-        if self.error.is_some() {
+    // reassignments of referenced bindings, e.g. `size = 8;`, run in the virtual program too
+    fn visit_expression_statement(&mut self, it: &mut ExpressionStatement<'alloc>) {
+        let pos = self.tmp_program_statement_buffer.last().unwrap().len();
+
+        oxc_ast_visit::walk_mut::walk_expression_statement(self, it);
+
+        let symbols = self.scopes.mutated_symbols(it.span);
+        if !symbols
+            .iter()
+            .any(|symbol| self.is_symbol_referenced(*symbol))
+            // cached values were read after the module ran, so they are already reassigned
+            || symbols.iter().any(|symbol| {
+                !self.scopes.is_local_symbol(*symbol)
+                    && self.value_cache.contains(self.scopes.symbol_name(*symbol))
+            })
+        {
+            return;
+        }
+
+        let references = self.scopes.statement_references(it);
+        if self.check_dynamic_variable_access(&references, it.span.start) {
             return;
         }
-        let idents = binding_pattern_kind_get_idents(&it.pattern.kind);
+        for reference in &references {
+            self.reference_variable(reference);
+        }
 
-        self.dynamic_variable_names
+        let mut statement = Statement::ExpressionStatement(
+            self.ast_builder.alloc(it.clone_in(self.allocator)),
+        );
+        replace_in_statement_using_spans(
+            self.ast_builder,
+            &mut statement,
+            &mut self.replacement_points,
+        );
+        self.scopes
+            .alias_replacer(self.ast_builder)
+            .visit_statement(&mut statement);
+
+        self.tmp_program_statement_buffer
             .last_mut()
             .unwrap()
-            .extend(idents);
+            .insert(pos, statement);
+    }
+
+    // parameters are only known at runtime, nothing in them is compiled
+    fn visit_formal_parameter(&mut self, _it: &mut oxc_ast::ast::FormalParameter<'alloc>) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    /// class names generated for the entrypoint, in source order
+    fn class_names(code: &str, class_name_template: &ClassNameTemplate) -> Result<Vec<String>, TransformError> {
+        let allocator = Allocator::default();
+        let ast_builder = AstBuilder::new(&allocator);
+        let mut program = Parser::new(&allocator, code, SourceType::tsx()).parse().program;
+        let mut value_cache = HashSet::new();
+
+        let mut transformer = VisitorTransformer::new(
+            &ast_builder,
+            &allocator,
+            true,
+            "store",
+            HashSet::new(),
+            "/p",
+            "/p/entry.tsx",
+            code,
+            &mut value_cache,
+            HashMap::new(),
+            HashSet::from(["css".to_string()]),
+            HashSet::new(),
+            HashSet::new(),
+            HashSet::new(),
+            HashSet::new(),
+            HashSet::new(),
+            HashSet::new(),
+            HashSet::new(),
+            class_name_template,
+            false,
+            false,
+        );
+        transformer.visit_program(&mut program);
+        if let Some(error) = transformer.error {
+            return Err(error);
+        }
+
+        Ok(program
+            .body
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::VariableDeclaration(declaration) => declaration.declarations[0].init.as_ref(),
+                _ => None,
+            })
+            .filter_map(|init| match init {
+                Expression::StringLiteral(class_name) => Some(class_name.value.to_string()),
+                _ => None,
+            })
+            .collect())
+    }
+
+    #[test]
+    fn test_class_names_follow_moved_lines() {
+        let template = ClassNameTemplate::parse("line[line]").unwrap();
+
+        let code = "import { css } from \"@style-this/core\";\nconst a = css`color: red;`;\n";
+        assert_eq!(class_names(code, &template).unwrap(), ["line2"]);
+
+        // `b` takes the line of `a`, which must not keep its cached name
+        let code = "import { css } from \"@style-this/core\";\nconst b = css`color: blue;`;\nconst a = css`color: red;`;\n";
+        assert_eq!(class_names(code, &template).unwrap(), ["line2", "line3"]);
+
+        // unchanged blocks are taken from the cache
+        assert_eq!(class_names(code, &template).unwrap(), ["line2", "line3"]);
     }
 }
@@ -10,7 +10,7 @@ pub fn resolve_err(
 ) {
     use itertools::Itertools;
 
-    let eval_ast = Parser::new(allocator, &eval_program, SourceType::default())
+    let eval_ast = Parser::new(allocator, eval_program, SourceType::default())
         .with_options(ParseOptions {
             parse_regular_expression: true,
             ..ParseOptions::default()
@@ -9,9 +9,18 @@ impl<'a, 'alloc> ReactStyledComponentTransformer<'a, 'alloc> {
         ast_builder: &'a AstBuilder<'alloc>,
         skip_jsx: bool,
         filepath: String,
+        styled_name: &'a str,
+        css_name: &'a str,
     ) -> Self {
         Self {
-            base: StyledComponentTransformer::new(ast_builder, skip_jsx, filepath, "className"),
+            base: StyledComponentTransformer::new(
+                ast_builder,
+                skip_jsx,
+                filepath,
+                "className",
+                styled_name,
+                css_name,
+            ),
         }
     }
 
@@ -32,10 +41,8 @@ impl<'a, 'alloc> ReactStyledComponentTransformer<'a, 'alloc> {
 
         let mut simple_tagged_template_expression =
             tagged_template_expression.clone_in(self.base.ast_builder.allocator);
-        simple_tagged_template_expression.tag = Expression::Identifier(
-            self.base.ast_builder
-                .alloc_identifier_reference(span, self.base.ast_builder.atom("css")),
-        );
+        simple_tagged_template_expression.tag =
+            ast::build_expression_path(self.base.ast_builder, span, self.base.css_name);
 
         let captured_expressions =
             self.base.extract_and_replace_arrow_functions(&mut simple_tagged_template_expression);
@@ -269,8 +276,8 @@ impl<'a, 'alloc> VisitMut<'alloc> for ReactStyledComponentTransformer<'a, 'alloc
     fn visit_expression(&mut self, it: &mut Expression<'alloc>) {
         if let Expression::TaggedTemplateExpression(tagged_template_expression) = &it
             && let Expression::CallExpression(call_expression) = &tagged_template_expression.tag
-            && let Expression::Identifier(identifier) = &call_expression.callee
-            && identifier.name == "styled"
+            && utils::expression_path(&call_expression.callee).as_deref()
+                == Some(self.base.styled_name)
             && call_expression.arguments.len() == 1
             && let oxc_ast::ast::Argument::Identifier(component_identifier) =
                 &call_expression.arguments[0]
@@ -293,8 +300,8 @@ impl<'a, 'alloc> VisitMut<'alloc> for ReactStyledComponentTransformer<'a, 'alloc
         if let Expression::TaggedTemplateExpression(tagged_template_expression) = it
             && let Expression::StaticMemberExpression(static_member_expression) =
                 &tagged_template_expression.tag
-            && let Expression::Identifier(object_identifier) = &static_member_expression.object
-            && object_identifier.name == "styled"
+            && utils::expression_path(&static_member_expression.object).as_deref()
+                == Some(self.base.styled_name)
         {
             let jsx_tag = static_member_expression.property.name.as_str();
             self.transform_styled_component(it, jsx_tag, false); // false = extending HTML element
@@ -322,7 +329,15 @@ pub fn react_prepass<'alloc>(
     filepath: String,
     program: &mut Program<'alloc>,
     skip_jsx: bool,
+    styled_name: &str,
+    css_name: &str,
 ) {
-    let mut transformer = ReactStyledComponentTransformer::new(ast_builder, skip_jsx, filepath);
+    let mut transformer = ReactStyledComponentTransformer::new(
+        ast_builder,
+        skip_jsx,
+        filepath,
+        styled_name,
+        css_name,
+    );
     transformer.visit_program(program);
 }
@@ -6,12 +6,6 @@ pub struct CapturedExpression<'alloc> {
     pub var_name: String,
 }
 
-pub struct ComponentTransformContext<'alloc> {
-    pub class_variable_name: String,
-    pub captured_expressions: Vec<CapturedExpression<'alloc>>,
-    pub span: oxc_span::Span,
-}
-
 pub struct StyledComponentTransformer<'a, 'alloc> {
     pub ast_builder: &'a AstBuilder<'alloc>,
     pub skip_jsx: bool,
@@ -21,6 +15,10 @@ pub struct StyledComponentTransformer<'a, 'alloc> {
     filepath: String,
     pub component_counter: usize,
     class_prop_name: &'static str,
+    /// `styled` or `ns.styled` for namespace imports
+    pub styled_name: &'a str,
+    /// tag of the generated css templates
+    pub css_name: &'a str,
 }
 
 impl<'a, 'alloc> StyledComponentTransformer<'a, 'alloc> {
@@ -29,6 +27,8 @@ impl<'a, 'alloc> StyledComponentTransformer<'a, 'alloc> {
         skip_jsx: bool,
         filepath: String,
         class_prop_name: &'static str,
+        styled_name: &'a str,
+        css_name: &'a str,
     ) -> Self {
         Self {
             ast_builder,
@@ -39,6 +39,8 @@ impl<'a, 'alloc> StyledComponentTransformer<'a, 'alloc> {
             anonymous_component_name_counter: 0,
             component_counter: 0,
             class_prop_name,
+            styled_name,
+            css_name,
         }
     }
 
@@ -188,18 +188,18 @@ impl SeededRandom {
         }
     }
 
+    /// The output only depends on the seed, it must stay the same across platforms and Rust
+    /// versions as class names are part of server rendered HTML: the seed is hashed with 64 bit
+    /// FNV-1a and drives an LCG, taking the high bits of each state
     pub fn random_string(&mut self, length: usize, seed: &str) -> String {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-
-        let mut hasher = DefaultHasher::new();
-        seed.hash(&mut hasher);
-        let mut rng_state = hasher.finish();
+        let mut rng_state = seed.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
 
         (0..length)
             .map(|_| {
                 rng_state = rng_state.wrapping_mul(1103515245).wrapping_add(12345);
-                let idx = (rng_state as usize) % self.chars.len();
+                let idx = ((rng_state >> 33) % self.chars.len() as u64) as usize;
                 self.chars[idx] as char
             })
             .collect()
@@ -260,90 +260,27 @@ impl SeededRandom {
     }
 }
 
-#[derive(Default)]
-struct ExpressionCollectorVisitor {
-    pub references: Vec<String>,
-    pub scopes_references: Vec<HashSet<String>>,
-}
-
-use oxc_ast_visit::walk::walk_formal_parameter;
-use oxc_ast_visit::walk::walk_identifier_reference;
-use oxc_ast_visit::walk::walk_variable_declarator;
-use oxc_syntax::scope::{ScopeFlags, ScopeId};
-
-impl<'a> Visit<'a> for ExpressionCollectorVisitor {
-    fn enter_scope(&mut self, _flags: ScopeFlags, _scope_id: &std::cell::Cell<Option<ScopeId>>) {
-        self.scopes_references.push(HashSet::new());
-    }
-    fn leave_scope(&mut self) {
-        self.scopes_references.pop();
-    }
-
-    fn visit_variable_declarator(&mut self, it: &oxc_ast::ast::VariableDeclarator<'a>) {
-        let Some(scope) = self.scopes_references.last_mut() else {
-            return;
-        };
-        scope.extend(binding_pattern_kind_get_idents(&it.id.kind));
-
-        walk_variable_declarator(self, it);
-    }
-    fn visit_formal_parameter(&mut self, it: &oxc_ast::ast::FormalParameter<'a>) {
-        let Some(scope) = self.scopes_references.last_mut() else {
-            return;
-        };
-        scope.extend(binding_pattern_kind_get_idents(&it.pattern.kind));
-
-        walk_formal_parameter(self, it);
-    }
-    fn visit_identifier_reference(&mut self, it: &oxc_ast::ast::IdentifierReference<'a>) {
-        let variable_name = &it.name;
-        for scope in self.scopes_references.iter() {
-            if scope.contains(variable_name.as_str()) {
-                return;
-            }
-        }
-        self.references.push(variable_name.to_string());
-
-        walk_identifier_reference(self, it);
-    }
-}
-
+/// returns `css` for `css`...`` and `st.css` for `st.css`...`` (namespace imports)
 pub fn tagged_template_get_tag<'alloc>(
     tagged_template_expression: &mut oxc_allocator::Box<
         'alloc,
         oxc_ast::ast::TaggedTemplateExpression<'alloc>,
     >,
-) -> Option<&'alloc str> {
-    let Expression::Identifier(identifier) = &mut tagged_template_expression.tag else {
-        return None;
-    };
-    Some(identifier.name.as_str())
+) -> Option<String> {
+    expression_path(&tagged_template_expression.tag)
 }
 
-pub fn statement_get_references<'a>(statement: &Statement<'a>) -> Vec<String> {
-    let mut visitor = ExpressionCollectorVisitor {
-        ..Default::default()
-    };
-    visitor.visit_statement(statement);
-    visitor.references
-}
-
-pub fn expression_get_references<'a>(expression: &Expression<'a>) -> Vec<String> {
-    let mut visitor = ExpressionCollectorVisitor {
-        ..Default::default()
-    };
-    visitor.visit_expression(expression);
-    visitor.references
-}
-
-pub fn tagged_template_expression_get_references<'a>(
-    it: &TaggedTemplateExpression<'a>,
-) -> Vec<String> {
-    let mut visitor = ExpressionCollectorVisitor {
-        ..Default::default()
-    };
-    visitor.visit_tagged_template_expression(it);
-    visitor.references
+/// dotted path of identifiers and static member accesses, e.g. `st.styled`
+pub fn expression_path(expression: &Expression) -> Option<String> {
+    match expression {
+        Expression::Identifier(identifier) => Some(identifier.name.to_string()),
+        Expression::StaticMemberExpression(member) => Some(format!(
+            "{}.{}",
+            expression_path(&member.object)?,
+            member.property.name
+        )),
+        _ => None,
+    }
 }
 
 pub fn build_new_ast<'a>(allocator: &'a Allocator) -> oxc_parser::ParserReturn<'a> {
@@ -485,7 +422,7 @@ pub fn replace_in_expression_using_spans<'alloc>(
     }
 
     t.ast_builder = Some(ast_builder);
-    t.replacement_points = Some(unsafe { std::mem::transmute(replacement_points) });
+    t.replacement_points = Some(replacement_points);
 
     t.visit_expression(expression);
 
@@ -511,7 +448,7 @@ pub fn replace_in_statement_using_spans<'alloc>(
     }
 
     t.ast_builder = Some(ast_builder);
-    t.replacement_points = Some(unsafe { std::mem::transmute(replacement_points) });
+    t.replacement_points = Some(replacement_points);
 
     t.visit_statement(statement);
 
@@ -537,7 +474,7 @@ pub fn replace_in_class_body_using_spans<'alloc>(
     }
 
     t.ast_builder = Some(ast_builder);
-    t.replacement_points = Some(unsafe { std::mem::transmute(replacement_points) });
+    t.replacement_points = Some(replacement_points);
 
     t.visit_class_body(class_body);
 
@@ -566,58 +503,3 @@ impl<'a, 'alloc> VisitMut<'alloc> for SpanReplacer<'a, 'alloc> {
         oxc_ast_visit::walk_mut::walk_expression(self, it);
     }
 }
-
-struct IdentifierReplacer<'a, 'alloc, F>
-where
-    F: Fn(&str) -> Option<String>,
-{
-    ast_builder: Option<&'a AstBuilder<'alloc>>,
-    get_replacement: Option<&'a F>,
-}
-
-pub fn replace_in_expression_using_identifiers<'alloc, F>(
-    ast_builder: &AstBuilder<'alloc>,
-    expression: &mut Expression<'alloc>,
-    get_replacement: &F,
-) where
-    F: Fn(&str) -> Option<String>,
-{
-    let mut t = IdentifierReplacer {
-        ast_builder: None,
-        get_replacement: None,
-    };
-
-    t.ast_builder = Some(ast_builder);
-    t.get_replacement = Some(get_replacement);
-
-    t.visit_expression(expression);
-
-    #[allow(unused_assignments)]
-    {
-        t.ast_builder = None;
-        t.get_replacement = None;
-    }
-}
-
-impl<'a, 'alloc, F> VisitMut<'alloc> for IdentifierReplacer<'a, 'alloc, F>
-where
-    F: Fn(&str) -> Option<String>,
-{
-    fn visit_identifier_reference(&mut self, it: &mut oxc_ast::ast::IdentifierReference<'alloc>) {
-        if let Some(replacement) = self.get_replacement.as_ref().unwrap()(&it.name) {
-            it.name = self.ast_builder.unwrap().atom(&replacement);
-        }
-    }
-
-    fn visit_binding_identifier(&mut self, it: &mut oxc_ast::ast::BindingIdentifier<'alloc>) {
-        if let Some(replacement) = self.get_replacement.as_ref().unwrap()(&it.name) {
-            it.name = self.ast_builder.unwrap().atom(&replacement);
-        }
-    }
-
-    fn visit_identifier_name(&mut self, it: &mut oxc_ast::ast::IdentifierName<'alloc>) {
-        if let Some(replacement) = self.get_replacement.as_ref().unwrap()(&it.name) {
-            it.name = self.ast_builder.unwrap().atom(&replacement);
-        }
-    }
-}
@@ -1,30 +1,75 @@
-import initWasm, { initialize, css_to_atomic_class_list, get_atomic_css, clear_atomic_css_cache, extract_non_atomic_css_js } from "../native/pkg/style_this.js";
+import initWasm, { initialize, css_to_atomic_class_list, css_to_atomic_class_keys_js, get_atomic_css, clear_atomic_css_cache, extract_non_atomic_css_js, module_cache_stats as _module_cache_stats, clear_module_cache, release_class_names, atomic_sync_remove_file } from "../native/pkg/style_this.js";
 import wasm from "../native/pkg/style_this_bg.wasm";
 
 import { Transformer as _Transformer } from "../native/pkg/style_this.js";
 
 // Re-export atomic CSS functions
-export { css_to_atomic_class_list, get_atomic_css, clear_atomic_css_cache, extract_non_atomic_css_js };
+export { css_to_atomic_class_list, css_to_atomic_class_keys_js, get_atomic_css, clear_atomic_css_cache, extract_non_atomic_css_js };
 
-export interface Transformer extends Omit<_Transformer, "transform"> {
+// Frees the class names of deleted files
+export { release_class_names };
+
+// Module cache debugging helpers
+export { clear_module_cache };
+/** `hits` counts dependencies evaluated without parsing them, `misses` ones that were parsed */
+export const module_cache_stats = _module_cache_stats as () => {
+  hits: number;
+  misses: number;
+  modules: number;
+};
+
+export interface Transformer
+  extends Omit<_Transformer, "transform" | "transform_many"> {
   transform(
     code: string,
     filepath: string,
     skipCssEval: boolean,
     importSource?: string,
-  ): Promise<
-    | {
-        code: string;
-        sourcemap: string;
-      }
-    | undefined
-  >;
+    /** the code is only part of the file, e.g. a Svelte `<script>`, unused css blocks aren't reported */
+    partialSource?: boolean,
+  ): Promise<TransformResult | undefined>;
+  /** transforms files together, sharing loaded dependency sources and evaluated values */
+  transform_many(
+    files: Array<{ code: string; filepath: string; importSource?: string; partialSource?: boolean }>,
+    skipCssEval: boolean,
+  ): Promise<Array<TransformResult | Error | undefined>>;
 }
 
+export type TransformResult =
+  | {
+      code: string;
+      sourcemap: string;
+      warnings?: TransformWarning[];
+    }
+  | SelfContainedTransformResult;
+
+/** Result of `transform` when the transformer was created with `returnCss: true`. */
+export type SelfContainedTransformResult = {
+  code: string;
+  map: string;
+  css: string;
+  cssMap?: string;
+  /** contents of the `.style-this.js` module in atomic mode */
+  atomicModule?: string;
+  warnings?: TransformWarning[];
+};
+
+/** A problem in a transformed file which doesn't stop the transform, e.g. an unused `css` block */
+export type TransformWarning = {
+  message: string;
+  filepath: string;
+  /** 1-based */
+  line: number;
+  /** 1-based */
+  column: number;
+};
+
 export type CssSourceMapData = Array<{
   className: string;
   start: number;
   end: number;
+  /** where the block starts in the CSS when it isn't `.className`, e.g. `@keyframes name` */
+  selector?: string;
 }>;
 
 export type CssCachEntry = Promise<string | Error> & {
@@ -36,23 +81,70 @@ export type CssCachEntry = Promise<string | Error> & {
   code: string;
 };
 
+/** export names of a module re-exporting the style-this APIs */
+export type LibraryImport = {
+  css?: string;
+  style?: string;
+  extraClass?: string;
+  keyframes?: string;
+  globalCss?: string;
+  createTokens?: string;
+  compose?: string;
+  cx?: string;
+  styled?: string;
+  /** which framework `styled` components are created for, defaults to `"react"` */
+  framework?: "react" | "solid";
+};
+
+/** a variant every styled file is evaluated again for, with some modules replaced */
+export type Theme = {
+  name: string;
+  /** class the theme's CSS is scoped under, defaults to `theme-<name>` */
+  className?: string;
+  /** import specifier or resolved file path -> module loaded instead, e.g. `{ "./tokens/light": "./tokens/dark" }` */
+  modules: Record<string, string>;
+};
+
 // fix types on rust-generated types
 export const Transformer = _Transformer as any as new (opts: {
   cwd: string;
+  /** class name hashes use file paths relative to it, defaults to `cwd` */
+  projectRoot?: string;
   ignoredImports: Record<string, string[]>;
+  /** module specifier -> exports, e.g. `{ "@acme/ui/styles": { css: "css", styled: "styled" } }` */
+  libraryImports?: Record<string, LibraryImport>;
+  /** not supported in atomic mode */
+  themes?: Theme[];
 
   loadFile: (filepath: string, importer: string) => Promise<[string, string]>;
   createRequire?: (filename: string) => NodeRequire;
 
-  cssCache: Map<string, CssCachEntry>;
+  /** not used when `returnCss` is set */
+  cssCache?: Map<string, CssCachEntry>;
   valueCache: Record<string, Record<string, any>>;
 
   wrapSelectorsWithGlobal?: boolean;
+  /**
+   * pattern of generated class names, defaults to `"[name]-[hash:6]"`. Placeholders are
+   * `[name]`, `[file]`, `[dir]`, `[line]` and `[hash:N]`, two blocks generating the same name are
+   * an error
+   */
+  classNameTemplate?: string;
+  /** mixed into every class name hash, e.g. to keep two builds sharing a page apart */
+  hashSalt?: string;
 
   cssExtension: string;
+  /** file extensions imported as raw text (in addition to `?raw` imports) */
+  rawExtensions?: string[];
   useRequire?: boolean;
   debug?: boolean;
   atomic?: boolean;
+  /** compile `style` templates to React style objects, on in files using `@style-this/react` */
+  styleObjects?: boolean;
+  /** leave the CSS of `css` blocks which are never referenced out of the output, on by default */
+  removeUnusedCss?: boolean;
+  /** return CSS and its source map from `transform` instead of resolving `cssCache` entries */
+  returnCss?: boolean;
 }) => Transformer;
 
 export const initializeStyleThis = async () => {
@@ -60,3 +152,19 @@ export const initializeStyleThis = async () => {
   await initWasm({ module_or_path: instance });
   initialize();
 };
+
+/** makes the atomic CSS helpers available to the evaluated programs of atomic transformers */
+export const registerAtomicHelpers = () => {
+  const helpers = {
+    __styleThis_cssToAtomicClassList: css_to_atomic_class_list,
+    __styleThis_cssToAtomicClassKeys: css_to_atomic_class_keys_js,
+    __styleThis_getAtomicCss: get_atomic_css,
+    __styleThis_extractNonAtomicCss: extract_non_atomic_css_js,
+    __styleThis_atomicSyncRemoveFile: atomic_sync_remove_file,
+  };
+  Object.assign(globalThis, helpers);
+  // also set on global for compatibility
+  if (typeof global !== "undefined") {
+    Object.assign(global, helpers);
+  }
+};
@@ -31,7 +31,7 @@ export function generateCssSourceMap(
 
   // For each CSS class, find its position in the generated CSS and map it back
   for (const entry of sourcemapData) {
-    const selector = `.${entry.className}`;
+    const selector = entry.selector ?? `.${entry.className}`;
     const position = findSelectorInCss(cssContent, selector);
 
     if (position) {
@@ -1,17 +1,87 @@
 export type CSSProperties = {
-  [key: string]: string | number | CSSProperties;
+  [key: string]: string | number | Array<string | number> | CSSProperties;
 };
 
 type CSS = string & {
   css: string;
 };
 
-export const css = (..._raw: any): CSS => {
+/**
+ * Creates a class from a template (`` css`color: red;` ``) or a style object
+ * (`css({ color: "red", "&:hover": { color: "blue" } })`).
+ *
+ * Props and state used inside a component's template become custom properties, which the
+ * transform sets in the `style` of the JSX elements using the class in `className`/`class`.
+ */
+export const css = Object.assign(
+  (..._raw: any): CSS => {
+    throw new Error(
+      "@style-this: called 'css' at runtime. This indicates an error in the transform.",
+    );
+  },
+  {
+    /** same as {@link globalCss} */
+    global: (..._raw: any): void => globalCss(),
+  },
+);
+
+/**
+ * Emits unscoped CSS (`` globalCss`body { margin: 0; }` ``), replacing the deprecated
+ * `_Global` variable name prefix.
+ */
+export const globalCss = (..._raw: any): void => {
+  throw new Error(
+    "@style-this: called 'globalCss' at runtime. This indicates an error in the transform.",
+  );
+};
+
+/**
+ * Defines an animation (`` keyframes`from { opacity: 0; } to { opacity: 1; }` ``) and returns
+ * its generated name, to be interpolated into `animation` declarations.
+ */
+export const keyframes = (..._raw: any): string => {
   throw new Error(
-    "@style-this: called 'css' at runtime. This indicates an error in the transform.",
+    "@style-this: called 'keyframes' at runtime. This indicates an error in the transform.",
   );
 };
 
+export type TokenValues = {
+  [key: string]: string | number | TokenValues;
+};
+
+/** the token object with every value replaced by its `var(--...)` reference */
+export type Tokens<T extends TokenValues> = {
+  [K in keyof T]: T[K] extends TokenValues ? Tokens<T[K]> : string;
+};
+
+/**
+ * Declares design tokens as custom properties on `:root`
+ * (`createTokens({ color: { primary: "tomato" } })`) and returns the same object holding
+ * `var(--...)` references, e.g. `tokens.color.primary`. Override them in a theme class by
+ * redeclaring the custom properties.
+ */
+export const createTokens = <T extends TokenValues>(_tokens: T): Tokens<T> => {
+  throw new Error(
+    "@style-this: called 'createTokens' at runtime. This indicates an error in the transform.",
+  );
+};
+
+/**
+ * Includes the declarations of other css blocks in a template
+ * (`` css`${compose(base)}; color: red;` ``). The blocks are merged at build time, so the
+ * resulting class contains the composed declarations followed by its own.
+ */
+export const compose = (..._blocks: CSS[]): string => {
+  throw new Error(
+    "@style-this: called 'compose' at runtime. This indicates an error in the transform.",
+  );
+};
+
+/**
+ * CSS declarations to interpolate into css templates. In React mode (files using
+ * `@style-this/react` or the `styleObjects` option) it compiles to a style object instead,
+ * e.g. `` style`font-size: ${size}px;` `` -> `{ fontSize: `${size}px` }`.
+ */
 export const style = (
   s: TemplateStringsArray,
   ...expr: Array<string | number | CSSProperties>
@@ -24,6 +94,34 @@ export const style = (
   return res;
 };
 
+/**
+ * Joins class names, skipping falsy values (`cx(button, active && activeButton)`). Calls are
+ * compiled to a string or a string concatenation at build time. In atomic mode, classes
+ * setting the same property as a later one are dropped, so the last argument wins.
+ */
+export const cx = (
+  ...classes: Array<string | number | false | null | undefined>
+): string => {
+  // registered by the atomic modules of transformed files
+  const keys: Record<string, string> =
+    (globalThis as any).__styleThisAtomicClassKeys ?? {};
+  const byKey = new Map<string, string>();
+  for (const list of classes) {
+    if (!list) continue;
+    for (const name of String(list).split(/\s+/)) {
+      if (!name) continue;
+      const key = keys[name] ?? name;
+      byKey.delete(key);
+      byKey.set(key, name);
+    }
+  }
+  return [...byKey.values()].join(" ");
+};
+
+/**
+ * Adds classes to the class list of a css block (`` css`${extraClass("card", utilities.card)}` ``).
+ * Arguments have to be known at build time, e.g. constants or imported values.
+ */
 export const extraClass = (..._classes: string[]): string => {
   throw new Error(
     "@style-this: called 'extraClass' at runtime. This indicates an error in the transform.",
@@ -1,5 +1,5 @@
 import path from "path";
-import { initializeStyleThis } from "@style-this/core/compiler";
+import { initializeStyleThis, registerAtomicHelpers } from "@style-this/core/compiler";
 import { cssFiles } from "../shared";
 import { dependencyStore } from "../shared";
 import { Transformer } from "@style-this/core/compiler";
@@ -29,6 +29,10 @@ const turbopackTransformLoader: LoaderType = function (code, inputSourceMap) {
     if (!styleThis) {
       await initializeStyleThis();
       const requireFn = createRequire(import.meta.url);
+      const atomic = process.env.ATOMIC === '1';
+      if (atomic) {
+        registerAtomicHelpers();
+      }
       styleThis = new Transformer({
         cwd: process.cwd(),
         ignoredImports: {},
@@ -39,7 +43,7 @@ const turbopackTransformLoader: LoaderType = function (code, inputSourceMap) {
         cssExtension,
         wrapSelectorsWithGlobal: true,
         useRequire: true,
-        atomic: process.env.ATOMIC === '1',
+        atomic,
       });
     }
 
@@ -1,5 +1,5 @@
 import type { RawLoaderDefinitionFunction } from "webpack";
-import { initializeStyleThis } from "@style-this/core/compiler";
+import { initializeStyleThis, registerAtomicHelpers } from "@style-this/core/compiler";
 import { cssFiles } from "../shared";
 import { Transformer } from "@style-this/core/compiler";
 import { createRequire } from "node:module";
@@ -31,6 +31,10 @@ const webpackTransformLoader: LoaderType = function (code, inputSourceMap) {
     if (!styleThis) {
       await initializeStyleThis();
       const requireFn = createRequire(import.meta.url);
+      const atomic = process.env.ATOMIC === '1';
+      if (atomic) {
+        registerAtomicHelpers();
+      }
       styleThis = new Transformer({
         cwd: process.cwd(),
         ignoredImports: {},
@@ -39,7 +43,7 @@ const webpackTransformLoader: LoaderType = function (code, inputSourceMap) {
         cssCache: cssFiles,
         valueCache: exportCache,
         cssExtension,
-        atomic: process.env.ATOMIC === '1',
+        atomic,
       });
     }
 
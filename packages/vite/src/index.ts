@@ -4,8 +4,11 @@ import { readFile } from "fs/promises";
 import {
   Transformer,
   initializeStyleThis,
+  registerAtomicHelpers,
   CssCachEntry,
   CssSourceMapData,
+  LibraryImport,
+  Theme,
 } from "@style-this/core/compiler";
 import { generateCssSourceMap } from "@style-this/core/cssSourceMap";
 import { createRequire } from "node:module";
@@ -31,8 +34,23 @@ interface Options {
   cssExtension?: string;
   filter?: Filter | Filter[];
   ignoredImports?: Record<string, true | (string | typeof DefaultImport)[]>;
+  /** modules re-exporting `css`, `styled` etc., e.g. a design system */
+  libraryImports?: Record<string, LibraryImport>;
+  /** emit the CSS of each theme, evaluated with its modules, scoped under the theme class */
+  themes?: Theme[];
+  /** pattern of generated class names, e.g. `"[file]_[name]_[hash:4]"` in dev and `"[hash:8]"` for production */
+  classNameTemplate?: string;
+  /** mixed into every class name hash */
+  hashSalt?: string;
+  /** class name hashes use file paths relative to it, e.g. the workspace root of a monorepo, defaults to the cwd */
+  projectRoot?: string;
   debug?: boolean;
   atomic?: boolean;
+  /** compile `style` templates to React style objects in every file */
+  styleObjects?: boolean;
+  /** drop the CSS of `css` blocks which are never referenced, on by default, unused blocks are always warned about. Svelte components are skipped since only their script is transformed */
+  removeUnusedCss?: boolean;
+  rawExtensions?: string[];
 }
 
 interface ViteConfig extends Pick<UserConfig, "optimizeDeps"> { }
@@ -186,13 +204,21 @@ const vitePlugin = (options: Options = {}) => {
 
       styleThis = new Transformer({
         cwd,
+        projectRoot: options.projectRoot,
         ignoredImports: options.ignoredImports as Record<string, string[]>,
+        libraryImports: options.libraryImports,
+        themes: options.themes,
+        classNameTemplate: options.classNameTemplate,
+        hashSalt: options.hashSalt,
+        styleObjects: options.styleObjects,
+        removeUnusedCss: options.removeUnusedCss,
 
         loadFile,
         cssCache,
         valueCache,
 
         cssExtension,
+        rawExtensions: options.rawExtensions,
 
         useRequire: (options as any).useRequire,
         debug,
@@ -201,16 +227,7 @@ const vitePlugin = (options: Options = {}) => {
 
       // In atomic mode, set up global helper functions
       if (atomic) {
-        const { css_to_atomic_class_list, get_atomic_css, extract_non_atomic_css_js, atomic_sync_remove_file } = await import("@style-this/core/compiler");
-        (globalThis as any).__styleThis_cssToAtomicClassList = css_to_atomic_class_list;
-        (globalThis as any).__styleThis_getAtomicCss = get_atomic_css;
-        (globalThis as any).__styleThis_extractNonAtomicCss = extract_non_atomic_css_js;
-        (globalThis as any).__styleThis_atomicSyncRemoveFile = atomic_sync_remove_file;
-        // Also set on global for compatibility
-        (global as any).__styleThis_cssToAtomicClassList = css_to_atomic_class_list;
-        (global as any).__styleThis_getAtomicCss = get_atomic_css;
-        (global as any).__styleThis_extractNonAtomicCss = extract_non_atomic_css_js;
-        (global as any).__styleThis_atomicSyncRemoveFile = atomic_sync_remove_file;
+        registerAtomicHelpers();
       }
     },
 
@@ -360,6 +377,14 @@ const vitePlugin = (options: Options = {}) => {
       }
     },
 
+    async watchChange(id, change) {
+      if (change.event !== "delete") return;
+
+      // other files may generate the class names of a deleted one
+      const { release_class_names } = await import("@style-this/core/compiler");
+      release_class_names(id.endsWith(".svelte") ? id.replace(/\.svelte$/, ".ts") : id);
+    },
+
     async handleHotUpdate(ctx) {
       if (!watchedFiles.has(ctx.file)) return;
 
@@ -506,6 +531,8 @@ const vitePlugin = (options: Options = {}) => {
           filepathForRust,
           skipCssEval,
           importSource,
+          // markup outside of the script can use css blocks the script doesn't
+          isSvelte,
         );
         const endTime = performance.now();
         const transformTime = endTime - startTime;
@@ -521,6 +548,15 @@ const vitePlugin = (options: Options = {}) => {
         }
         watchedFiles.add(filepath);
 
+        for (const warning of transformedResult.warnings ?? []) {
+          this.warn({
+            message: warning.message,
+            id: filepath,
+            // rollup columns are 0-based
+            loc: { line: warning.line, column: warning.column - 1 },
+          });
+        }
+
         // during dev, invalidate the virtual CSS module
         if (server) {
           const virtualModuleId = resolvedVirtualModulePrefix + cssFilepath;
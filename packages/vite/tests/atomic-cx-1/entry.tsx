@@ -0,0 +1,13 @@
+import { css, cx } from "@style-this/core";
+
+const a = css`
+  color: red;
+  padding: 4px;
+`;
+
+const b = css`
+  color: blue;
+`;
+
+// b's color wins over a's, merged at build time
+export const merged = cx(a, b);
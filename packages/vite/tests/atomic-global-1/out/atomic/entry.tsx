@@ -1,10 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import "virtual:style-this:/entry.tsx.atomic.css";
-import * as _styleThisClasses from "virtual:style-this:/home/shiro/project/style-this/packages/vite/tests/atomic-global-1/entry.tsx.style-this.js";
-import "virtual:style-this:/home/shiro/project/style-this/packages/vite/tests/atomic-global-1/entry.tsx.css";
-// Regular style
-const mainStyle = _styleThisClasses._styleThis_mainStyle;
-// Global style with _Global prefix
-const _GlobalMain = _styleThisClasses._styleThis__GlobalMain;
-export { mainStyle, _GlobalMain };
@@ -1,9 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import "@style-this/core/atomic";
-import "virtual:style-this:/entry.tsx.css";
-// Regular style
-const mainStyle = "mainStyle-wheb81";
-// Global style with _Global prefix
-const _GlobalMain = "_GlobalMain";
-export { mainStyle, _GlobalMain };
@@ -1,11 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import "virtual:style-this:/entry.tsx.atomic.css";
-import * as _styleThisClasses from "virtual:style-this:/home/shiro/project/style-this/packages/vite/tests/atomic-media-query-1/entry.tsx.style-this.js";
-import "virtual:style-this:/home/shiro/project/style-this/packages/vite/tests/atomic-media-query-1/entry.tsx.css";
-// Inner component with base styles
-const innerStyle = _styleThisClasses._styleThis_innerStyle;
-// Outer component - wraps inner and overrides background to coral
-// Uses CSS selector to target inner component
-const outerStyle = _styleThisClasses._styleThis_outerStyle;
-export { outerStyle, innerStyle };
@@ -1,37 +0,0 @@
-// {
-//   "version": 3,
-//   "file": "/home/shiro/project/style-this/packages/vite/tests/atomic-media-query-1/entry.tsx.css",
-//   "sources": [
-//     "/home/shiro/project/style-this/packages/vite/tests/atomic-media-query-1/entry.tsx"
-//   ],
-//   "sourcesContent": [
-//     "import { css } from \"@style-this/core\";\nimport \"@style-this/core/atomic\";\n\n// Inner component with base styles\nconst innerStyle = css`\n  background: red;\n  padding: 10px;\n`;\n\n// Outer component - wraps inner and overrides background to coral\n// Uses CSS selector to target inner component\nconst outerStyle = css`\n  width: 100%;\n  .${innerStyle} {\n    background: coral;\n  }\n  @media (max-width: 500px) {\n    .${innerStyle} {\n      background: blue;\n    }\n  }\n`;\n\nexport { outerStyle, innerStyle };\n"
-//   ],
-//   "names": [],
-//   "mappings": "AAImB;;;AAOA"
-// }
-
-import { css } from "@style-this/core";
-import "@style-this/core/atomic";
-
-// Inner component with base styles
-const innerStyle = css`
-  background: red;
-  padding: 10px;
-`;
-
-// Outer component - wraps inner and overrides background to coral
-// Uses CSS selector to target inner component
-const outerStyle = css`
-  width: 100%;
-  .${innerStyle} {
-    background: coral;
-  }
-  @media (max-width: 500px) {
-    .${innerStyle} {
-      background: blue;
-    }
-  }
-`;
-
-export { outerStyle, innerStyle };
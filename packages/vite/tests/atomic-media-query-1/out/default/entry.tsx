@@ -1,10 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import "@style-this/core/atomic";
-import "virtual:style-this:/entry.tsx.css";
-// Inner component with base styles
-const innerStyle = "innerStyle-2zo1az";
-// Outer component - wraps inner and overrides background to coral
-// Uses CSS selector to target inner component
-const outerStyle = "outerStyle-z41ifw";
-export { outerStyle, innerStyle };
@@ -1,42 +0,0 @@
-// /packages/vite/tests/basic-1/entry.tsx: {"a", "css"}
-const a = new String('a-q3g9a7');
-"use strict";
-let { css } = require("/packages/core/dist/index.mjs");
-a.css = `background: red;`;
-// Import atomic CSS helpers from wasm
-const cssToAtomicClassList = global.__styleThis_cssToAtomicClassList;
-const extractNonAtomicCss = global.__styleThis_extractNonAtomicCss;
-if (!cssToAtomicClassList) {
-    throw new Error('cssToAtomicClassList not found on global. Available: ' + Object.keys(global).filter(k => k.includes('styleThis')).join(', '));
-}
-if (!extractNonAtomicCss) {
-    throw new Error('extractNonAtomicCss not found on global. Available: ' + Object.keys(global).filter(k => k.includes('styleThis')).join(', '));
-}
-
-// Convert CSS to atomic class lists
-if (!a.css) { console.error('[atomic] a.css is undefined'); a.css = ''; }
-const _a_atomic = cssToAtomicClassList(a.css);
-
-const cssSourcemapData = [{className:'a-q3g9a7',start:51,end:76}];
-
-// In atomic mode, resolve per-file CSS with marker classes for sourcemaps
-// The actual atomic CSS goes into the .atomic.css file via explicit import
-const perFileCss = [`.a-q3g9a7 {\n${extractNonAtomicCss(a.css)||'/* atomized */'}\n}`].join('\n');
-global.__styleThis_css_aabbbccc.get('/packages/vite/tests/basic-1/entry.tsx.css').resolve(perFileCss, cssSourcemapData, '/packages/vite/tests/basic-1/entry.tsx.css');
-
-// Generate and resolve the .style-this.js module
-const styleThisModule = 'export const _styleThis_a = "' + 'a-q3g9a7' + ' ' + _a_atomic + '";';
-global.__styleThis_css_aabbbccc.get('/packages/vite/tests/basic-1/entry.tsx.style-this.js').resolve(styleThisModule);
-
-
-// /packages/vite/tests/basic-1/entry.tsx: {"css", "a"}
-"use strict";
-let { css } = require("/packages/core/dist/index.mjs");
-let a = new String("a-q3g9a7");
-a.css = `background: red;`;
-const cssSourcemapData = [{className:'a-q3g9a7',start:51,end:76}];
-global.__styleThis_css_aabbbccc.get('/packages/vite/tests/basic-1/entry.tsx.css').resolve([
-`.a-q3g9a7 {
-${a.css}
-}`
-].join('\n'), cssSourcemapData, '/packages/vite/tests/basic-1/entry.tsx.css');
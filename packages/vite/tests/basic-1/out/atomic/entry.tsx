@@ -1,5 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import * as _styleThisClasses from "virtual:style-this:/entry.tsx.style-this.js";
-import "virtual:style-this:/home/shiro/project/style-this/packages/vite/tests/basic-1/entry.tsx.css";
-const a = _styleThisClasses._styleThis_a;
@@ -1,4 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import "virtual:style-this:/entry.tsx.css";
-const a = "a-q3g9a7";
@@ -1,18 +0,0 @@
-// {
-//   "version": 3,
-//   "file": "/home/shiro/project/style-this/packages/vite/tests/basic-1/entry.tsx.css",
-//   "sources": [
-//     "/home/shiro/project/style-this/packages/vite/tests/basic-1/entry.tsx"
-//   ],
-//   "sourcesContent": [
-//     "import { css } from \"@style-this/core\";\n\nconst a = css`\n  background: red;\n`;\n"
-//   ],
-//   "names": [],
-//   "mappings": "AAEU"
-// }
-
-import { css } from "@style-this/core";
-
-const a = css`
-  background: red;
-`;
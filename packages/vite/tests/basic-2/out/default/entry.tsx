@@ -1,6 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import "virtual:style-this:/entry.tsx.css";
-const foo = "red";
-const a = "a-ole3wx";
-const b = "b-r4x6jg";
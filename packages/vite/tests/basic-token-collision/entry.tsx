@@ -0,0 +1,8 @@
+import { createTokens } from "@style-this/core";
+
+export const tokens = createTokens({
+  spacing: {
+    "a.b": "4px",
+    "a-b": "8px",
+  },
+});
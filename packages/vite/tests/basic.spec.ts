@@ -1,5 +1,6 @@
 import { describe, expect, test } from "vitest";
-import { evaluateProgramBothModes, getResolver } from "./util/testUtil";
+import { readFile } from "fs/promises";
+import { evaluateProgramBothModes, getResolver, transformWithCss } from "./util/testUtil";
 import { setupPlugin } from "./util/testUtil";
 
 describe("basic", () => {
@@ -21,6 +22,14 @@ describe("basic", () => {
       "failed to parse program",
     );
   });
+  test("basic-token-collision", async (ctx) => {
+    const testDir = `${__dirname}/${ctx.task.name}`;
+    const resolver = await getResolver(testDir);
+
+    await expect(evaluateProgramBothModes(testDir, "entry.tsx", resolver)).rejects.toThrow(
+      'createTokens tokens ["spacing"]["a.b"] and ["spacing"]["a-b"] both map to',
+    );
+  });
 });
 
 describe("atomic with media queries", () => {
@@ -38,3 +47,26 @@ describe("atomic with global styles", () => {
     await evaluateProgramBothModes(testDir, "entry.tsx", resolver);
   });
 });
+
+describe("atomic with cx", () => {
+  test("atomic-cx-1", async (ctx) => {
+    const testDir = `${__dirname}/${ctx.task.name}`;
+    const resolver = await getResolver(testDir);
+    await evaluateProgramBothModes(testDir, "entry.tsx", resolver);
+
+    const plugin = await setupPlugin(resolver, { atomic: true });
+    const entryFilepath = `${testDir}/entry.tsx`;
+    const result = await plugin.transform(await readFile(entryFilepath, "utf-8"), entryFilepath);
+
+    // the class of a, its padding, the class of b and its color, a's color is dropped
+    const merged = result?.code.match(/merged = "([^"]*)"/)?.[1];
+    expect(result?.code).not.toContain("cx(");
+    expect(merged?.split(" ")).toHaveLength(4);
+
+    // outside of atomic mode both blocks keep all of their declarations
+    const { css } = await transformWithCss(testDir, "entry.tsx", resolver);
+    expect(css).toContain("color: red");
+    expect(css).toContain("padding: 4px");
+    expect(css).toContain("color: blue");
+  });
+});
@@ -1,30 +0,0 @@
-// @ts-nocheck
-import { css, style } from "@style-this/core";
-import mib, * as a from "./a";
-import * as _styleThisClasses from "virtual:style-this:/entry.tsx.style-this.js";
-import "virtual:style-this:/home/shiro/project/style-this/packages/vite/tests/correctness-1/entry.tsx.css";
-const mutate = (v) => v;
-const a1 = () => {
-	const a2 = () => {
-		const a3 = () => {
-			return _styleThisClasses._styleThis_expression_11;
-		};
-		return [0];
-	};
-	const wi = a2();
-	const m = _styleThisClasses._styleThis_var_m_7;
-};
-const b = _styleThisClasses._styleThis_b;
-const comp = () => {
-("foob");
-	const b = () => {
-		const c = () => _styleThisClasses._styleThis_expression_6;
-		const s1 = _styleThisClasses._styleThis_var_s1_4;
-	};
-	mutate(b);
-	return _styleThisClasses._styleThis_expression_3;
-};
-const { color } = { color: "blue" };
-const st = undefined;
-const s2 = _styleThisClasses._styleThis_s2;
-const unrelated = _styleThisClasses._styleThis_unrelated;
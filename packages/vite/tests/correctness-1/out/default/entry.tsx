@@ -1,29 +0,0 @@
-// @ts-nocheck
-import { css, style } from "@style-this/core";
-import mib, * as a from "./a";
-import "virtual:style-this:/entry.tsx.css";
-const mutate = (v) => v;
-const a1 = () => {
-	const a2 = () => {
-		const a3 = () => {
-			return "__styleThis_expression_11-g5yzc1";
-		};
-		return [0];
-	};
-	const wi = a2();
-	const m = "m-t2jk5y";
-};
-const b = "b-0hyvwp";
-const comp = () => {
-("foob");
-	const b = () => {
-		const c = () => "__styleThis_expression_6-t2j4xy";
-		const s1 = "s1-9ebsxi";
-	};
-	mutate(b);
-	return "__styleThis_expression_3-xirstq";
-};
-const { color } = { color: "blue" };
-const st = undefined;
-const s2 = "s2-f0l6r0";
-const unrelated = "unrelated-h6bsx2";
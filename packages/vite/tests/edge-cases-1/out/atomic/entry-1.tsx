@@ -1,6 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import { num } from "./shared";
-import * as _styleThisClasses from "virtual:style-this:/entry-1.tsx.style-this.js";
-import "virtual:style-this:/home/shiro/project/style-this/packages/vite/tests/edge-cases-1/entry-1.tsx.css";
-const a = _styleThisClasses._styleThis_a;
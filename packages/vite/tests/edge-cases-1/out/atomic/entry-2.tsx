@@ -1,6 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import { num } from "./shared";
-import * as _styleThisClasses from "virtual:style-this:/entry-2.tsx.style-this.js";
-import "virtual:style-this:/home/shiro/project/style-this/packages/vite/tests/edge-cases-1/entry-2.tsx.css";
-const b = _styleThisClasses._styleThis_b;
@@ -1,6 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import * as _styleThisClasses from "virtual:style-this:/shared.tsx.style-this.js";
-import "virtual:style-this:/home/shiro/project/style-this/packages/vite/tests/edge-cases-1/shared.tsx.css";
-export const num = Math.random();
-const shared = _styleThisClasses._styleThis_shared;
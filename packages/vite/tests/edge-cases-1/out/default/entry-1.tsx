@@ -1,5 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import { num } from "./shared";
-import "virtual:style-this:/entry-1.tsx.css";
-const a = "a-fody7g";
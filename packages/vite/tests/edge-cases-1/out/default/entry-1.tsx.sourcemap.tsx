@@ -1,19 +0,0 @@
-// {
-//   "version": 3,
-//   "file": "/home/shiro/project/style-this/packages/vite/tests/edge-cases-1/entry-1.tsx.css",
-//   "sources": [
-//     "/home/shiro/project/style-this/packages/vite/tests/edge-cases-1/entry-1.tsx"
-//   ],
-//   "sourcesContent": [
-//     "import { css } from \"@style-this/core\";\nimport { num } from \"./shared\";\n\nconst a = css`\n  margin: ${num}px;\n`;\n"
-//   ],
-//   "names": [],
-//   "mappings": "AAGU"
-// }
-
-import { css } from "@style-this/core";
-import { num } from "./shared";
-
-const a = css`
-  margin: ${num}px;
-`;
@@ -1,5 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import { num } from "./shared";
-import "virtual:style-this:/entry-2.tsx.css";
-const b = "b-d2joxu";
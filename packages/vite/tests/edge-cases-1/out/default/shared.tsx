@@ -1,5 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import "virtual:style-this:/shared.tsx.css";
-export const num = Math.random();
-const shared = "shared-52bkdy";
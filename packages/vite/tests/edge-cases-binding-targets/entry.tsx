@@ -0,0 +1,25 @@
+import { css } from "@style-this/core";
+
+const color = "red";
+
+// destructured bindings used to crash the transform
+const { a } = css`
+  color: ${color};
+`;
+
+export class Modal {
+  cls: string;
+
+  constructor() {
+    this.cls = css`
+      background: ${color};
+    `;
+  }
+}
+
+export const styles: Record<string, string> = {};
+styles.x = css`
+  border-color: ${color};
+`;
+
+export { a };
@@ -0,0 +1,11 @@
+import { css } from "@style-this/core";
+import { css as dsCss, tokens } from "./ui";
+
+// the same role imported from two libraries, neither import shadows the other
+export const base = css`
+  color: ${tokens.primary};
+`;
+
+export const accent = dsCss`
+  color: ${tokens.secondary};
+`;
@@ -0,0 +1,11 @@
+import { css, tokens } from "./ui";
+import * as ui from "./ui";
+
+// non-API exports of a library module are evaluated like any other import
+export const title = css`
+  color: ${tokens.primary};
+`;
+
+export const subtitle = ui.css`
+  color: ${ui.tokens.secondary};
+`;
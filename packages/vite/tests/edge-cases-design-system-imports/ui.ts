@@ -0,0 +1,6 @@
+export { css } from "@style-this/core";
+
+export const tokens = {
+  primary: "tomato",
+  secondary: "teal",
+};
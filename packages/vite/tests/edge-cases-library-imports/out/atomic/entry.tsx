@@ -1,14 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import { getNumber } from "some_lib";
-import * as _styleThisClasses from "virtual:style-this:/entry.tsx.style-this.js";
-import "virtual:style-this:/home/shiro/project/style-this/packages/vite/tests/edge-cases-library-imports/entry.tsx.css";
-// const someLib = require("vite");
-// const someLib = require("some_lib");
-// const someLib = require("../../src/viteTests/edge-cases-library-imports/node_modules/some_lib/index");
-// const someLib = require("/home/shiro/project/stylistic/src/viteTests/edge-cases-library-imports/node_modules/some_lib/index.js");
-// const someLib = require("some_lib/index.js");
-// const someLib = require("../../src/viteTests/edge-cases-library-imports/hi.js");
-// const { getNumber } = someLib;
-// const { getNumber } = require("some_lib/index.js");
-const a = _styleThisClasses._styleThis_a;
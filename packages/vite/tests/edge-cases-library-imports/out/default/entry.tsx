@@ -1,13 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import { getNumber } from "some_lib";
-import "virtual:style-this:/entry.tsx.css";
-// const someLib = require("vite");
-// const someLib = require("some_lib");
-// const someLib = require("../../src/viteTests/edge-cases-library-imports/node_modules/some_lib/index");
-// const someLib = require("/home/shiro/project/stylistic/src/viteTests/edge-cases-library-imports/node_modules/some_lib/index.js");
-// const someLib = require("some_lib/index.js");
-// const someLib = require("../../src/viteTests/edge-cases-library-imports/hi.js");
-// const { getNumber } = someLib;
-// const { getNumber } = require("some_lib/index.js");
-const a = "a-wtyj0x";
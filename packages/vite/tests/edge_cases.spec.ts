@@ -1,5 +1,9 @@
-import { describe, test } from "vitest";
-import { evaluateProgramBothModesMultiFile, getResolver } from "./util/testUtil";
+import { describe, expect, test } from "vitest";
+import {
+  evaluateProgramBothModesMultiFile,
+  getResolver,
+  transformWithCss,
+} from "./util/testUtil";
 import { join } from "path";
 
 describe("edge-cases", () => {
@@ -17,4 +21,38 @@ describe("edge-cases", () => {
 
     await evaluateProgramBothModesMultiFile(testDir, ["entry.tsx"], resolver);
   });
+
+  test("edge-cases-design-system-imports", async (ctx) => {
+    const testDir = `${__dirname}/${ctx.task.name}`;
+    const resolver = await getResolver(testDir);
+
+    const options = { libraryImports: { "./ui": { css: "css" } } };
+    await evaluateProgramBothModesMultiFile(testDir, ["entry.tsx", "aliases.tsx"], resolver, options);
+
+    // a named and a namespace import of the same library
+    const entry = await transformWithCss(testDir, "entry.tsx", resolver, options);
+    expect(entry.code).not.toMatch(/css`/);
+    expect(entry.css).toContain("color: tomato");
+    expect(entry.css).toContain("color: teal");
+
+    // the core package and a design system both providing `css`
+    const aliases = await transformWithCss(testDir, "aliases.tsx", resolver, options);
+    expect(aliases.code).not.toMatch(/(css|dsCss)`/);
+    expect(aliases.css).toContain("color: tomato");
+    expect(aliases.css).toContain("color: teal");
+  });
+
+  test("edge-cases-binding-targets", async (ctx) => {
+    const testDir = `${__dirname}/${ctx.task.name}`;
+    const resolver = await getResolver(testDir);
+
+    await evaluateProgramBothModesMultiFile(testDir, ["entry.tsx"], resolver);
+
+    // destructured, class field and member assigned blocks are all compiled
+    const { code, css } = await transformWithCss(testDir, "entry.tsx", resolver);
+    expect(code).not.toMatch(/css`/);
+    expect(css).toContain("color: red");
+    expect(css).toContain("background: red");
+    expect(css).toContain("border-color: red");
+  });
 });
@@ -1,9 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import * as _styleThisClasses from "virtual:style-this:/entry.tsx.style-this.js";
-import "virtual:style-this:/home/shiro/project/style-this/packages/vite/tests/expressions-1/entry.tsx.css";
-const doPromise = async () => {
-	await new Promise((resolve) => setTimeout(resolve, 10));
-	return "red";
-};
-const a = _styleThisClasses._styleThis_a;
@@ -1,18 +0,0 @@
-// {
-//   "version": 3,
-//   "file": "/home/shiro/project/style-this/packages/vite/tests/expressions-2/entry.tsx.css",
-//   "sources": [
-//     "/home/shiro/project/style-this/packages/vite/tests/expressions-2/entry.tsx"
-//   ],
-//   "sourcesContent": [
-//     "import { css } from \"@style-this/core\";\n\nconst a = css`\n  margin: ${() => 99}px;\n`;\n"
-//   ],
-//   "names": [],
-//   "mappings": "AAEU"
-// }
-
-import { css } from "@style-this/core";
-
-const a = css`
-  margin: ${() => 99}px;
-`;
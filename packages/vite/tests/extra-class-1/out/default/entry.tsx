@@ -1,5 +0,0 @@
-// @ts-nocheck
-import { css, extraClass } from "@style-this/core";
-import "virtual:style-this:/entry.tsx.css";
-const a = "a-chuvkp foo bar";
-const b = "b-1iv0ta baz";
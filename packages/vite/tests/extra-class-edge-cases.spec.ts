@@ -1,5 +1,5 @@
-import { describe, test } from "vitest";
-import { evaluateProgramBothModes, getResolver } from "./util/testUtil";
+import { describe, expect, test } from "vitest";
+import { evaluateProgramBothModes, getResolver, transformWithCss } from "./util/testUtil";
 
 describe("extra-class-edge-cases", () => {
   test("extra-class-edge-cases", async (ctx) => {
@@ -7,4 +7,19 @@ describe("extra-class-edge-cases", () => {
     const resolver = await getResolver(testDir);
     await evaluateProgramBothModes(testDir, "entry.tsx", resolver);
   });
+
+  test("extra-class-evaluated", async (ctx) => {
+    const testDir = `${__dirname}/${ctx.task.name}`;
+    const resolver = await getResolver(testDir);
+    await evaluateProgramBothModes(testDir, "entry.tsx", resolver);
+
+    // the imported classes are part of the class lists, not of the CSS
+    const { code, css } = await transformWithCss(testDir, "entry.tsx", resolver);
+    expect(code).not.toContain("extraClass(");
+    expect(code).toContain("bordered rounded shadow");
+    expect(code).toContain("text-gray");
+    expect(css).toContain("padding: 8px");
+    expect(css).toContain("font-size: 12px");
+    expect(css).not.toContain("rounded");
+  });
 });
@@ -0,0 +1,17 @@
+import { css, extraClass } from "@style-this/core";
+import { utilities } from "./utilities";
+
+// imported extra classes are evaluated at build time, the output only holds the class list
+export const card = css`
+  ${extraClass("bordered", utilities.card)}
+  padding: 8px;
+`;
+
+export const Muted = () => (
+  <span
+    className={css`
+      ${extraClass(utilities.muted)}
+      font-size: 12px;
+    `}
+  />
+);
@@ -0,0 +1,4 @@
+export const utilities = {
+  card: "rounded shadow",
+  muted: "text-gray",
+};
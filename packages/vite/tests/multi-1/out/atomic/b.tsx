@@ -1,6 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import * as _styleThisClasses from "virtual:style-this:/b.tsx.style-this.js";
-import "virtual:style-this:/home/shiro/project/style-this/packages/vite/tests/multi-1/b.tsx.css";
-export const color = "red";
-const exported = _styleThisClasses._styleThis_exported;
@@ -1,86 +0,0 @@
-// /packages/vite/tests/multi-1/b.tsx (/packages/vite/tests/multi-1/entry.tsx): {"color", "css", "exported"}
-"use strict";
-let { css } = require("/packages/core/dist/index.mjs");
-let color = "red";
-let exported = new String("exported-9qr0lu");
-exported.css = `background: ${color};`;
-
-global.__styleThis_vars_aabbbccc["/packages/vite/tests/multi-1/b.tsx"] = {...(global.__styleThis_vars_aabbbccc["/packages/vite/tests/multi-1/b.tsx"] ?? {}), color};
-
-// /packages/vite/tests/multi-1/b.tsx: {"exported", "css", "color"}
-const exported = new String('exported-9qr0lu');
-"use strict";
-let { css } = require("/packages/core/dist/index.mjs");
-let color = global.__styleThis_vars_aabbbccc["/packages/vite/tests/multi-1/b.tsx"]['color'];
-exported.css = `background: ${color};`;
-
-global.__styleThis_vars_aabbbccc["/packages/vite/tests/multi-1/b.tsx"] = {...(global.__styleThis_vars_aabbbccc["/packages/vite/tests/multi-1/b.tsx"] ?? {}), color};// Import atomic CSS helpers from wasm
-const cssToAtomicClassList = global.__styleThis_cssToAtomicClassList;
-const extractNonAtomicCss = global.__styleThis_extractNonAtomicCss;
-if (!cssToAtomicClassList) {
-    throw new Error('cssToAtomicClassList not found on global. Available: ' + Object.keys(global).filter(k => k.includes('styleThis')).join(', '));
-}
-if (!extractNonAtomicCss) {
-    throw new Error('extractNonAtomicCss not found on global. Available: ' + Object.keys(global).filter(k => k.includes('styleThis')).join(', '));
-}
-
-// Convert CSS to atomic class lists
-if (!exported.css) { console.error('[atomic] exported.css is undefined'); exported.css = ''; }
-const _exported_atomic = cssToAtomicClassList(exported.css);
-
-const cssSourcemapData = [{className:'exported-9qr0lu',start:87,end:117}];
-
-// In atomic mode, resolve per-file CSS with marker classes for sourcemaps
-// The actual atomic CSS goes into the .atomic.css file via explicit import
-const perFileCss = [`.exported-9qr0lu {\n${extractNonAtomicCss(exported.css)||'/* atomized */'}\n}`].join('\n');
-global.__styleThis_css_aabbbccc.get('/packages/vite/tests/multi-1/b.tsx.css').resolve(perFileCss, cssSourcemapData, '/packages/vite/tests/multi-1/b.tsx.css');
-
-// Generate and resolve the .style-this.js module
-const styleThisModule = 'export const _styleThis_exported = "' + 'exported-9qr0lu' + ' ' + _exported_atomic + '";';
-global.__styleThis_css_aabbbccc.get('/packages/vite/tests/multi-1/b.tsx.style-this.js').resolve(styleThisModule);
-
-
-// /packages/vite/tests/multi-1/entry.tsx: {"a", "css", "color"}
-const a = new String('a-5y38xq');
-"use strict";
-const color = __styleThis_vars_aabbbccc["/packages/vite/tests/multi-1/b.tsx"]["color"];
-let { css } = require("/packages/core/dist/index.mjs");
-a.css = `background: ${color};`;
-// Import atomic CSS helpers from wasm
-const cssToAtomicClassList = global.__styleThis_cssToAtomicClassList;
-const extractNonAtomicCss = global.__styleThis_extractNonAtomicCss;
-if (!cssToAtomicClassList) {
-    throw new Error('cssToAtomicClassList not found on global. Available: ' + Object.keys(global).filter(k => k.includes('styleThis')).join(', '));
-}
-if (!extractNonAtomicCss) {
-    throw new Error('extractNonAtomicCss not found on global. Available: ' + Object.keys(global).filter(k => k.includes('styleThis')).join(', '));
-}
-
-// Convert CSS to atomic class lists
-if (!a.css) { console.error('[atomic] a.css is undefined'); a.css = ''; }
-const _a_atomic = cssToAtomicClassList(a.css);
-
-const cssSourcemapData = [{className:'a-5y38xq',start:80,end:110}];
-
-// In atomic mode, resolve per-file CSS with marker classes for sourcemaps
-// The actual atomic CSS goes into the .atomic.css file via explicit import
-const perFileCss = [`.a-5y38xq {\n${extractNonAtomicCss(a.css)||'/* atomized */'}\n}`].join('\n');
-global.__styleThis_css_aabbbccc.get('/packages/vite/tests/multi-1/entry.tsx.css').resolve(perFileCss, cssSourcemapData, '/packages/vite/tests/multi-1/entry.tsx.css');
-
-// Generate and resolve the .style-this.js module
-const styleThisModule = 'export const _styleThis_a = "' + 'a-5y38xq' + ' ' + _a_atomic + '";';
-global.__styleThis_css_aabbbccc.get('/packages/vite/tests/multi-1/entry.tsx.style-this.js').resolve(styleThisModule);
-
-
-// /packages/vite/tests/multi-1/entry.tsx: {"css", "color", "a"}
-"use strict";
-const color = __styleThis_vars_aabbbccc["/packages/vite/tests/multi-1/b.tsx"]["color"];
-let { css } = require("/packages/core/dist/index.mjs");
-let a = new String("a-5y38xq");
-a.css = `background: ${color};`;
-const cssSourcemapData = [{className:'a-5y38xq',start:80,end:110}];
-global.__styleThis_css_aabbbccc.get('/packages/vite/tests/multi-1/entry.tsx.css').resolve([
-`.a-5y38xq {
-${a.css}
-}`
-].join('\n'), cssSourcemapData, '/packages/vite/tests/multi-1/entry.tsx.css');
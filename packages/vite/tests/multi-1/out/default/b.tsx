@@ -1,5 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import "virtual:style-this:/b.tsx.css";
-export const color = "red";
-const exported = "exported-9qr0lu";
@@ -1,36 +0,0 @@
-// /packages/vite/tests/multi-1/b.tsx (/packages/vite/tests/multi-1/entry.tsx): {"color", "css", "exported"}
-"use strict";
-let { css } = require("/packages/core/dist/index.mjs");
-let color = "red";
-let exported = new String("exported-9qr0lu");
-exported.css = `background: ${color};`;
-
-global.__styleThis_vars_aabbbccc["/packages/vite/tests/multi-1/b.tsx"] = {...(global.__styleThis_vars_aabbbccc["/packages/vite/tests/multi-1/b.tsx"] ?? {}), color};
-
-// /packages/vite/tests/multi-1/b.tsx: {"exported", "css", "color"}
-"use strict";
-let { css } = require("/packages/core/dist/index.mjs");
-let color = global.__styleThis_vars_aabbbccc["/packages/vite/tests/multi-1/b.tsx"]['color'];
-let exported = new String("exported-9qr0lu");
-exported.css = `background: ${color};`;
-
-global.__styleThis_vars_aabbbccc["/packages/vite/tests/multi-1/b.tsx"] = {...(global.__styleThis_vars_aabbbccc["/packages/vite/tests/multi-1/b.tsx"] ?? {}), color};const cssSourcemapData = [{className:'exported-9qr0lu',start:87,end:117}];
-global.__styleThis_css_aabbbccc.get('/packages/vite/tests/multi-1/b.tsx.css').resolve([
-`.exported-9qr0lu {
-${exported.css}
-}`
-].join('\n'), cssSourcemapData, '/packages/vite/tests/multi-1/b.tsx.css');
-
-
-// /packages/vite/tests/multi-1/entry.tsx: {"css", "color", "a"}
-"use strict";
-const color = __styleThis_vars_aabbbccc["/packages/vite/tests/multi-1/b.tsx"]["color"];
-let { css } = require("/packages/core/dist/index.mjs");
-let a = new String("a-5y38xq");
-a.css = `background: ${color};`;
-const cssSourcemapData = [{className:'a-5y38xq',start:80,end:110}];
-global.__styleThis_css_aabbbccc.get('/packages/vite/tests/multi-1/entry.tsx.css').resolve([
-`.a-5y38xq {
-${a.css}
-}`
-].join('\n'), cssSourcemapData, '/packages/vite/tests/multi-1/entry.tsx.css');
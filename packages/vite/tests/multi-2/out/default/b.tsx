@@ -1,6 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import { color as originalColor } from "./c";
-import "virtual:style-this:/b.tsx.css";
-export const color = "hot" + originalColor;
-const exported = "exported-45274t";
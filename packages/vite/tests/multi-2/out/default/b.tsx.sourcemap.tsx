@@ -1,21 +0,0 @@
-// {
-//   "version": 3,
-//   "file": "/home/shiro/project/style-this/packages/vite/tests/multi-2/b.tsx.css",
-//   "sources": [
-//     "/home/shiro/project/style-this/packages/vite/tests/multi-2/b.tsx"
-//   ],
-//   "sourcesContent": [
-//     "import { css } from \"@style-this/core\";\nimport { color as originalColor } from \"./c\";\n\nexport const color = \"hot\" + originalColor;\n\nconst exported = css`\n  background: ${color};\n`;\n"
-//   ],
-//   "names": [],
-//   "mappings": "AAKiB"
-// }
-
-import { css } from "@style-this/core";
-import { color as originalColor } from "./c";
-
-export const color = "hot" + originalColor;
-
-const exported = css`
-  background: ${color};
-`;
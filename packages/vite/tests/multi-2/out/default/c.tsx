@@ -1,5 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import "virtual:style-this:/c.tsx.css";
-export const color = "pink";
-const exported = "exported-iv0de3";
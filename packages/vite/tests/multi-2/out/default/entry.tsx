@@ -1,5 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import { color } from "./b";
-import "virtual:style-this:/entry.tsx.css";
-const a = "a-c1evwh";
@@ -0,0 +1,5 @@
+import { css } from "@style-this/core";
+
+export const button = css`
+  color: red;
+`;
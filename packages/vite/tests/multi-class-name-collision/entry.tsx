@@ -0,0 +1,6 @@
+import { css } from "@style-this/core";
+import { button as otherButton } from "./b";
+
+export const button = css`
+  color: ${otherButton};
+`;
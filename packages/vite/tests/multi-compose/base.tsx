@@ -0,0 +1,6 @@
+import { css } from "@style-this/core";
+
+export const card = css`
+  border-radius: 4px;
+  padding: 8px;
+`;
@@ -0,0 +1,13 @@
+import { compose, css } from "@style-this/core";
+import { card } from "./base";
+
+const highlight = css`
+  outline: 1px solid gold;
+`;
+
+// composes a block of this file and one of another module, both are evaluated before the
+// composing template
+export const featuredCard = css`
+  ${compose(card, highlight)};
+  background: white;
+`;
@@ -1,6 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import * as _styleThisClasses from "virtual:style-this:/b.tsx.style-this.js";
-import "virtual:style-this:/home/shiro/project/style-this/packages/vite/tests/multi-contextual-overrides/b.tsx.css";
-export const color = "blue";
-export const inner = _styleThisClasses._styleThis_inner;
@@ -1,21 +0,0 @@
-// {
-//   "version": 3,
-//   "file": "/home/shiro/project/style-this/packages/vite/tests/multi-contextual-overrides/entry.tsx.css",
-//   "sources": [
-//     "/home/shiro/project/style-this/packages/vite/tests/multi-contextual-overrides/entry.tsx"
-//   ],
-//   "sourcesContent": [
-//     "import { css } from \"@style-this/core\";\nimport { inner } from \"./b\";\n\nconst outer = css`\n  .${inner} {\n    background: \"red\";\n  }\n`;\n"
-//   ],
-//   "names": [],
-//   "mappings": "AAGc"
-// }
-
-import { css } from "@style-this/core";
-import { inner } from "./b";
-
-const outer = css`
-  .${inner} {
-    background: "red";
-  }
-`;
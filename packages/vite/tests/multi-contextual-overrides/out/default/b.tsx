@@ -1,5 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import "virtual:style-this:/b.tsx.css";
-export const color = "blue";
-export const inner = "inner-v4l63w";
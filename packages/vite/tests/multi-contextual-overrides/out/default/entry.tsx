@@ -1,5 +0,0 @@
-// @ts-nocheck
-import { css } from "@style-this/core";
-import { inner } from "./b";
-import "virtual:style-this:/entry.tsx.css";
-const outer = "outer-yvgxuz";
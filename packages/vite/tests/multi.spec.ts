@@ -1,5 +1,5 @@
-import { describe, test } from "vitest";
-import { evaluateProgramBothModesMultiFile, getResolver } from "./util/testUtil";
+import { describe, expect, test } from "vitest";
+import { evaluateProgramBothModesMultiFile, getResolver, transformWithCss } from "./util/testUtil";
 
 describe("multi", () => {
   test("multi-1", async (ctx) => {
@@ -22,4 +22,32 @@ describe("multi", () => {
 
     await evaluateProgramBothModesMultiFile(testDir, ["entry.tsx", "b.tsx"], resolver);
   });
+
+  test("multi-compose", async (ctx) => {
+    const testDir = `${__dirname}/${ctx.task.name}`;
+    const resolver = await getResolver(testDir);
+
+    await evaluateProgramBothModesMultiFile(testDir, ["entry.tsx", "base.tsx"], resolver);
+
+    // the declarations of both composed blocks are part of the composing block
+    const { code, css } = await transformWithCss(testDir, "entry.tsx", resolver);
+    expect(code).not.toContain("compose(");
+    const start = css!.lastIndexOf("{", css!.indexOf("background: white"));
+    const featuredCard = css!.slice(start, css!.indexOf("}", start));
+    expect(featuredCard).toContain("border-radius: 4px");
+    expect(featuredCard).toContain("padding: 8px");
+    expect(featuredCard).toContain("outline: 1px solid gold");
+  });
+
+  test("multi-class-name-collision", async (ctx) => {
+    const testDir = `${__dirname}/${ctx.task.name}`;
+    const resolver = await getResolver(testDir);
+
+    // without [hash] both files generate `button`, which must not depend on the build order
+    await expect(
+      evaluateProgramBothModesMultiFile(testDir, ["entry.tsx", "b.tsx"], resolver, {
+        classNameTemplate: "[name]",
+      }),
+    ).rejects.toThrow("class name 'button' of");
+  });
 });
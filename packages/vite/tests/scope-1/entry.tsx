@@ -0,0 +1,43 @@
+import { css } from "@style-this/core";
+
+const color = "red";
+const size = 3;
+
+// a block scoped binding only shadows color within the block
+{
+  const color = "blue";
+  console.log(color);
+}
+
+export const outer = css`
+  color: ${color};
+`;
+
+// hoisted, and its parameter shadows the module level size
+export const padded = css`
+  padding: ${double(4)}px;
+`;
+
+function double(size: number) {
+  return size * 2;
+}
+
+export function Card() {
+  const color = "green";
+
+  return css`
+    color: ${color};
+    margin: ${size}px;
+  `;
+}
+
+export function Fallback() {
+  try {
+    throw new Error("failed");
+  } catch (color) {
+    // the catch parameter is only known at runtime
+    return css`
+      outline-color: ${String(color)};
+    `;
+  }
+}
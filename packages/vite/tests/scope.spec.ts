@@ -0,0 +1,22 @@
+import { describe, expect, test } from "vitest";
+import { evaluateProgramBothModes, getResolver, transformWithCss } from "./util/testUtil";
+
+describe("scope", () => {
+  test("scope-1", async (ctx) => {
+    const testDir = `${__dirname}/${ctx.task.name}`;
+    const resolver = await getResolver(testDir);
+    await evaluateProgramBothModes(testDir, "entry.tsx", resolver);
+
+    const { code, css } = await transformWithCss(testDir, "entry.tsx", resolver);
+
+    // every template reads the closest binding of the names it uses
+    expect(css).toContain("color: red");
+    expect(css).toContain("color: green");
+    expect(css).not.toContain("color: blue");
+    expect(css).toContain("padding: 8px");
+    expect(css).toContain("margin: 3px");
+    // the catch parameter becomes a custom property instead of the module level color
+    expect(css).toMatch(/outline-color: var\(--/);
+    expect(code).toContain("String(color)");
+  });
+});
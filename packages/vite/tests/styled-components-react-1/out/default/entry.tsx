@@ -1,20 +0,0 @@
-// @ts-nocheck
-import { styled } from "@style-this/react";
-import { css } from "@style-this/core";
-import "virtual:style-this:/entry.tsx.css";
-export const FancyButton = (() => {
-	let FancyButton = "FancyButton-t6j09m";
-	let comp = (props) => {
-		const { styleProps: _styleProps, style: _style,...rest } = props;
-		return <button {...rest} className={rest.className ? FancyButton + (" " + rest.className) : FancyButton} style={{
-			"--var1-2nwhuj": (({ a }) => a)({
-				...props.styleProps,
-				"props": props
-			}),
-			...props.style ?? {}
-		}} />;
-	};
-	comp.toString = () => FancyButton;
-	return comp;
-})();
-const unrelated = "unrelated-2rc96v";
@@ -0,0 +1,6 @@
+import { css } from "@style-this/core";
+import { colors } from "./tokens";
+
+export const title = css`
+  color: ${colors.text};
+`;
@@ -0,0 +1,6 @@
+import { css } from "@style-this/core";
+
+// doesn't import the tokens the theme replaces
+export const card = css`
+  padding: 4px;
+`;
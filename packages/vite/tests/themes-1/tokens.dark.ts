@@ -0,0 +1,3 @@
+export const colors = {
+  text: "white",
+};
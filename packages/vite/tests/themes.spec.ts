@@ -0,0 +1,22 @@
+import { describe, expect, test } from "vitest";
+import { getResolver, transformWithCss } from "./util/testUtil";
+
+describe("themes", () => {
+  test("themes-1", async (ctx) => {
+    const testDir = `${__dirname}/${ctx.task.name}`;
+    const resolver = await getResolver(testDir);
+    const options = {
+      themes: [{ name: "dark", modules: { "./tokens": "./tokens.dark" } }],
+    };
+
+    const entry = await transformWithCss(testDir, "entry.tsx", resolver, options);
+    expect(entry.css).toContain("color: black");
+    expect(entry.css).toContain(".theme-dark");
+    expect(entry.css).toContain("color: white");
+
+    // files the theme doesn't reach only have their regular CSS
+    const plain = await transformWithCss(testDir, "plain.tsx", resolver, options);
+    expect(plain.css).toContain("padding: 4px");
+    expect(plain.css).not.toContain(".theme-dark");
+  });
+});
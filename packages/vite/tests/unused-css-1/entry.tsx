@@ -0,0 +1,10 @@
+import { css } from "@style-this/core";
+
+export const used = css`
+  color: red;
+`;
+
+// never referenced, its CSS is left out
+const unused = css`
+  color: blue;
+`;
@@ -0,0 +1,48 @@
+import { describe, expect, test } from "vitest";
+import { readFile } from "fs/promises";
+import {
+  evaluateProgramBothModes,
+  getResolver,
+  setupPlugin,
+  transformWithCss,
+} from "./util/testUtil";
+
+describe("unused-css", () => {
+  test("unused-css-1", async (ctx) => {
+    const testDir = `${__dirname}/${ctx.task.name}`;
+    const resolver = await getResolver(testDir);
+    await evaluateProgramBothModes(testDir, "entry.tsx", resolver);
+
+    const plugin = await setupPlugin(resolver);
+    const entryFilepath = `${testDir}/entry.tsx`;
+    await plugin.transform(await readFile(entryFilepath, "utf-8"), entryFilepath);
+
+    expect(plugin.warn).toHaveBeenCalledTimes(1);
+    expect(plugin.warn.mock.calls[0][0].message).toBe("css block 'unused' is never used");
+
+    const { css } = await transformWithCss(testDir, "entry.tsx", resolver);
+    expect(css).toContain("color: red");
+    expect(css).not.toContain("color: blue");
+
+    // kept when removing unused blocks is turned off
+    const kept = await transformWithCss(testDir, "entry.tsx", resolver, { removeUnusedCss: false });
+    expect(kept.css).toContain("color: blue");
+  });
+
+  test("unused-css-svelte", async (ctx) => {
+    const testDir = `${__dirname}/${ctx.task.name}`;
+    const resolver = await getResolver(testDir);
+
+    const plugin = await setupPlugin(resolver);
+    const entryFilepath = `${testDir}/Button.svelte`;
+    const result = await plugin.transform(await readFile(entryFilepath, "utf-8"), entryFilepath);
+    expect(result?.code).toBeDefined();
+
+    // only the script is transformed, so the markup using the block can't be seen
+    expect(plugin.warn).not.toHaveBeenCalled();
+
+    const id = plugin.resolveId(`virtual:style-this:${testDir}/Button.ts.${plugin.cssExtension}`)!;
+    const css = await plugin.load(id);
+    expect(typeof css === "string" ? css : css?.code).toContain("color: red");
+  });
+});
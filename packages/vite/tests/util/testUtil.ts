@@ -230,15 +230,16 @@ export const evaluateProgramBothModes = async (
   testDir: string,
   entry: string,
   resolver: Record<string, string>,
+  options?: Record<string, any>,
 ) => {
   // Test with default mode
   resetRandom();
-  const defaultPlugin = await setupPlugin(resolver, { atomic: false });
+  const defaultPlugin = await setupPlugin(resolver, { ...options, atomic: false });
   await evaluateProgramWithMode(testDir, entry, defaultPlugin, "default");
 
   // Test with atomic mode
   resetRandom();
-  const atomicPlugin = await setupPlugin(resolver, { atomic: true });
+  const atomicPlugin = await setupPlugin(resolver, { ...options, atomic: true });
   await evaluateProgramWithMode(testDir, entry, atomicPlugin, "atomic");
 };
 
@@ -246,17 +247,18 @@ export const evaluateProgramBothModesMultiFile = async (
   testDir: string,
   entries: string[],
   resolver: Record<string, string>,
+  options?: Record<string, any>,
 ) => {
   // Test with default mode
   resetRandom();
-  const defaultPlugin = await setupPlugin(resolver, { atomic: false });
+  const defaultPlugin = await setupPlugin(resolver, { ...options, atomic: false });
   for (const entry of entries) {
     await evaluateProgramWithMode(testDir, entry, defaultPlugin, "default");
   }
 
   // Test with atomic mode
   resetRandom();
-  const atomicPlugin = await setupPlugin(resolver, { atomic: true });
+  const atomicPlugin = await setupPlugin(resolver, { ...options, atomic: true });
   for (const entry of entries) {
     await evaluateProgramWithMode(testDir, entry, atomicPlugin, "atomic");
   }
@@ -283,6 +285,26 @@ export const resetRandom = (() => {
 
 export const tsx = (raw: TemplateStringsArray) => raw.join("");
 
+/** transforms a file of the test directory and returns its code along with its CSS */
+export const transformWithCss = async (
+  testDir: string,
+  entry: string,
+  resolver: Record<string, string>,
+  options?: Record<string, any>,
+) => {
+  const plugin = await setupPlugin(resolver, options);
+  const entryFilepath = `${testDir}/${entry}`;
+  const result = await plugin.transform(await readFile(entryFilepath, "utf-8"), entryFilepath);
+
+  const id = plugin.resolveId(`virtual:style-this:${entryFilepath}.${plugin.cssExtension}`)!;
+  const css = await plugin.load(id);
+
+  return {
+    code: result?.code as string | undefined,
+    css: (typeof css === "string" ? css : css?.code) as string | undefined,
+  };
+};
+
 export const setupPlugin = async (resolver: Record<string, string>, options?: Record<string, any>) => {
   const pluginOptions = { debug: true, useRequire: true, ...(options || {}) };
   const plugin = vitePlugin(pluginOptions as any);
@@ -310,6 +332,7 @@ export const setupPlugin = async (resolver: Record<string, string>, options?: Re
       });
     },
     addWatchFile: vi.fn(),
+    warn: vi.fn(),
   } as any;
 
   const config = plugin.config.bind(ctx);
@@ -327,5 +350,6 @@ export const setupPlugin = async (resolver: Record<string, string>, options?: Re
     resolveId: plugin.resolveId.bind(ctx),
     load: plugin.load.bind(ctx),
     __getTemporaryPrograms: plugin.__getTemporaryPrograms.bind(ctx),
+    warn: ctx.warn as ReturnType<typeof vi.fn>,
   };
 };
@@ -1,5 +1,5 @@
 import type { RawLoaderDefinitionFunction } from "webpack";
-import { Transformer, initializeStyleThis } from "@style-this/core/compiler";
+import { Transformer, initializeStyleThis, registerAtomicHelpers } from "@style-this/core/compiler";
 import { cssFiles } from "./shared";
 import path from "path";
 import { readFile } from "fs/promises";
@@ -101,6 +101,8 @@ const webpack5Loader: Loader = function webpack5LoaderPlugin(
   (async () => {
     if (!styleThis) {
       await initializeStyleThis();
+      // atomic transformers evaluate programs using these
+      registerAtomicHelpers();
       styleThis = new Transformer({
         loadFile,
         cssFileStore: cssFiles,